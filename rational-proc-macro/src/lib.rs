@@ -30,7 +30,7 @@ pub fn rational(input: TokenStream) -> TokenStream {
 
     let rational: Rational = str
         .parse()
-        .unwrap_or_else(|_| panic!("Incorrect rational literal: {str}"));
+        .unwrap_or_else(|err| panic!("Incorrect rational literal: {str} ({err})"));
     let (p, q) = (rational.numerator(), rational.denominator());
 
     let expanded = quote! {
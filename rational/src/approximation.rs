@@ -0,0 +1,236 @@
+//! Best rational approximations using continued fractions.
+
+use std::cmp::min;
+
+use crate::{BigInt, BigRational, Integer, Ratio};
+
+/// Finds the fraction closest to `value` with |numerator| <= max_numerator and denominator <= max_denominator.
+///
+/// Both bounds must be positive, the numerator is unbounded when `max_numerator` is None.
+/// On a tie the convergent wins over the semiconvergent.
+pub(crate) fn best_approximation(
+    value: &BigRational,
+    max_numerator: Option<&BigInt>,
+    max_denominator: &BigInt,
+) -> BigRational {
+    let negative = value.is_negative();
+    let magnitude = Ratio::new(
+        BigInt::from_unsigned(value.numerator().unsigned_abs()),
+        value.denominator(),
+    );
+    let terms = RationalTerms {
+        n: magnitude.numerator(),
+        d: magnitude.denominator(),
+    };
+
+    let distance = |fraction: &BigRational| {
+        let difference = fraction.clone() - magnitude.clone();
+        if difference.is_negative() {
+            -difference
+        } else {
+            difference
+        }
+    };
+    let best = best_from_terms(terms, max_numerator, max_denominator, |a, b| {
+        distance(a) < distance(b)
+    });
+    if negative {
+        -best
+    } else {
+        best
+    }
+}
+
+/// Finds the fraction closest to `value` with denominator <= max_denominator, breaking ties like
+/// Python's `Fraction.limit_denominator`.
+///
+/// Python expands the signed value, starting from its floor, so a negative value isn't treated
+/// as the mirror image of its magnitude. Shifting by an integer doesn't change any denominator,
+/// so the floor is split off and the non-negative rest is approximated instead.
+pub(crate) fn best_with_denominator(value: &BigRational, max_denominator: &BigInt) -> BigRational {
+    let floor = Ratio::from(value.floor());
+    let fract = value.clone() - floor.clone();
+    floor + best_approximation(&fract, None, max_denominator)
+}
+
+/// Finds the fraction closest to the square root of a non-negative `value` which isn't the
+/// square of a rational, with the same bounds as [best_approximation].
+pub(crate) fn best_sqrt_approximation(
+    value: &BigRational,
+    max_numerator: Option<&BigInt>,
+    max_denominator: &BigInt,
+) -> BigRational {
+    // The root is irrational, so it's never exactly halfway between two fractions
+    let closer = |a: &BigRational, b: &BigRational| {
+        let midpoint = (a.clone() + b.clone()) / Ratio::from(BigInt::from(2));
+        let below_midpoint = *value < midpoint.clone() * midpoint;
+        (a < b) == below_midpoint
+    };
+    best_from_terms(
+        SqrtTerms::new(value),
+        max_numerator,
+        max_denominator,
+        closer,
+    )
+}
+
+/// The convergents of the square root of a non-negative `value` which isn't the square of a
+/// rational, an infinite sequence of increasingly precise approximations.
+pub(crate) fn sqrt_convergents(value: &BigRational) -> impl Iterator<Item = BigRational> {
+    let mut terms = SqrtTerms::new(value);
+    let (mut p0, mut q0) = (BigInt::zero(), BigInt::one());
+    let (mut p1, mut q1) = (BigInt::one(), BigInt::zero());
+    std::iter::from_fn(move || {
+        let a = terms.next()?;
+        let p2 = p0.clone() + a.clone() * p1.clone();
+        let q2 = q0.clone() + a * q1.clone();
+        (p0, q0, p1, q1) = (p1.clone(), q1.clone(), p2, q2);
+        Some(Ratio::new_unchecked(p1.clone(), q1.clone()))
+    })
+}
+
+/// Walks the convergents of a positive value given by its continued fraction `terms`, and picks
+/// the best approximation within bounds.
+///
+/// `closer(a, b)` has to tell whether `a` is strictly closer to the value than `b`.
+fn best_from_terms(
+    mut terms: impl Iterator<Item = BigInt>,
+    max_numerator: Option<&BigInt>,
+    max_denominator: &BigInt,
+    closer: impl Fn(&BigRational, &BigRational) -> bool,
+) -> BigRational {
+    // Two latest convergents p0 / q0 and p1 / q1
+    let (mut p0, mut q0) = (BigInt::zero(), BigInt::one());
+    let (mut p1, mut q1) = (BigInt::one(), BigInt::zero());
+
+    loop {
+        let Some(a) = terms.next() else {
+            // The expansion ended within bounds, so the last convergent is the value itself
+            return Ratio::new(p1, q1);
+        };
+        let p2 = p0.clone() + a.clone() * p1.clone();
+        let q2 = q0.clone() + a * q1.clone();
+
+        if max_numerator.is_some_and(|max| &p2 > max) || &q2 > max_denominator {
+            break;
+        };
+        (p0, q0, p1, q1) = (p1, q1, p2, q2);
+    }
+    // The best approximation is either the last convergent or the largest semiconvergent within bounds
+    let by_numerator = match max_numerator {
+        Some(max) if !p1.is_zero() => Some((max.clone() - p0.clone()) / p1.clone()),
+        _ => None,
+    };
+    let by_denominator =
+        (!q1.is_zero()).then(|| (max_denominator.clone() - q0.clone()) / q1.clone());
+    let k = match (by_numerator, by_denominator) {
+        (Some(a), Some(b)) => min(a, b),
+        (Some(k), None) | (None, Some(k)) => k,
+        (None, None) => unreachable!("The latest convergent is never 0/0"),
+    };
+    let q = q0 + k.clone() * q1.clone();
+    if q.is_zero() {
+        // With k = 0 right after the first convergent the semiconvergent is the initial 1/0
+        return Ratio::new(p1, q1);
+    };
+    let semiconvergent = Ratio::new(p0 + k * p1.clone(), q);
+    if q1.is_zero() {
+        return semiconvergent;
+    };
+    let convergent = Ratio::new(p1, q1);
+
+    if closer(&semiconvergent, &convergent) {
+        semiconvergent
+    } else {
+        convergent
+    }
+}
+
+/// Continued fraction terms of a non-negative rational, from the Euclidean algorithm.
+struct RationalTerms {
+    n: BigInt,
+    d: BigInt,
+}
+
+impl Iterator for RationalTerms {
+    type Item = BigInt;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.d.is_zero() {
+            return None;
+        };
+        let a = self.n.clone() / self.d.clone();
+        let remainder = self.n.clone() - a.clone() * self.d.clone();
+        self.n = std::mem::replace(&mut self.d, remainder);
+        Some(a)
+    }
+}
+
+/// Continued fraction terms of an irrational square root, which is kept in the exact form
+/// `(p + sqrt(d)) / q` with `q` dividing `d - p^2`.
+struct SqrtTerms {
+    p: BigInt,
+    q: BigInt,
+    d: BigInt,
+    /// The integer part of `sqrt(d)`.
+    root: BigInt,
+}
+
+impl SqrtTerms {
+    /// Starts from `sqrt(n / m) = sqrt(n * m) / m`.
+    fn new(value: &BigRational) -> Self {
+        let d = value.numerator() * value.denominator();
+        let root = BigInt::from_unsigned(d.magnitude().nth_root(2));
+        SqrtTerms {
+            p: BigInt::zero(),
+            q: value.denominator(),
+            d,
+            root,
+        }
+    }
+}
+
+impl Iterator for SqrtTerms {
+    type Item = BigInt;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Both q and p + sqrt(d) stay positive, so truncating division gives the floor
+        let a = (self.p.clone() + self.root.clone()) / self.q.clone();
+        self.p = a.clone() * self.q.clone() - self.p.clone();
+        self.q = (self.d.clone() - self.p.clone() * self.p.clone()) / self.q.clone();
+        Some(a)
+    }
+}
+
+impl<T: Integer> Ratio<T> {
+    /// Finds the closest fraction with a denominator of at most `max_denominator`.
+    ///
+    /// Works like Python's `Fraction.limit_denominator`: the result is the last continued fraction
+    /// convergent or semiconvergent within the bound, preferring the convergent on ties. As in
+    /// Python the expansion starts from the floor, so ties aren't symmetric around zero.
+    ///
+    /// ```
+    /// # use rational::*;
+    /// let pi = Ratio::<i64>::new(314159265358979, 100000000000000);
+    /// assert_eq!(pi.limit_denominator(10), Ratio::new(22, 7));
+    /// assert_eq!(pi.limit_denominator(1000), Ratio::new(355, 113));
+    /// assert_eq!(Ratio::<i64>::new(-1, 2).limit_denominator(1), Ratio::new(-1, 1));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_denominator` is less than 1.
+    pub fn limit_denominator(&self, max_denominator: T) -> Self {
+        assert!(
+            max_denominator >= T::one(),
+            "max_denominator must be at least 1"
+        );
+        if self.q <= max_denominator {
+            return self.clone();
+        };
+
+        let approximation = best_with_denominator(&self.to_big(), &max_denominator.to_big_int());
+        // Intermediate fractions never have a larger numerator than the value itself
+        Ratio::from_big(approximation).expect("Approximation is within bounds")
+    }
+}
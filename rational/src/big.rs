@@ -0,0 +1,743 @@
+//! Arbitrary-precision integers, used as the underlying storage of a [BigRational].
+
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Add, Div, Mul, Neg, Rem, Shl, Shr, Sub};
+use std::str::FromStr;
+
+use crate::integer::{sealed, Integer, Unsigned};
+use crate::Ratio;
+
+/// A rational number backed by arbitrary-precision integers, which never overflows.
+///
+/// ```
+/// # use rational::{BigInt, BigRational, Ratio};
+/// let mut a: BigRational = "0.(3)".parse().unwrap();
+/// for _ in 0..10 {
+///     a = a.clone() * a;
+/// }
+/// assert!(a < BigRational::from(BigInt::from(1u8)));
+///
+/// let small: Ratio<i64> = Ratio::new(1, 3);
+/// let big = BigRational::from(small);
+/// assert_eq!(Ratio::<i64>::try_from(big), Ok(small));
+/// ```
+pub type BigRational = Ratio<BigInt>;
+
+const LIMB_BITS: u32 = u32::BITS;
+
+/// Largest power of 10 fitting into a single limb, used for decimal conversions.
+const DECIMAL_BASE: u32 = 1_000_000_000;
+const DECIMAL_BASE_DIGITS: usize = 9;
+
+/// An arbitrary-precision non-negative integer.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BigUint {
+    /// Little-endian limbs, without trailing zeros. Zero is represented by no limbs at all.
+    limbs: Vec<u32>,
+}
+
+/// An arbitrary-precision signed integer.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BigInt {
+    /// Always false for zero.
+    negative: bool,
+    magnitude: BigUint,
+}
+
+/// The error returned when a string can't be parsed into a [BigUint] or a [BigInt].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseBigIntError(());
+
+/// The error returned when an arbitrary-precision number doesn't fit into a primitive type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TryFromBigIntError(());
+
+impl BigUint {
+    fn from_limbs(mut limbs: Vec<u32>) -> BigUint {
+        while limbs.last() == Some(&0) {
+            limbs.pop();
+        }
+        BigUint { limbs }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// Number of significant bits, zero for zero.
+    pub fn bits(&self) -> u64 {
+        match self.limbs.last() {
+            Some(last) => self.limbs.len() as u64 * LIMB_BITS as u64 - last.leading_zeros() as u64,
+            None => 0,
+        }
+    }
+
+    fn from_u128(mut value: u128) -> BigUint {
+        let mut limbs = Vec::new();
+        while value != 0 {
+            limbs.push(value as u32);
+            value >>= LIMB_BITS;
+        }
+        BigUint { limbs }
+    }
+
+    fn to_u128(&self) -> Option<u128> {
+        if self.limbs.len() > 4 {
+            return None;
+        };
+        Some(
+            self.limbs
+                .iter()
+                .rev()
+                .fold(0, |acc, &limb| (acc << LIMB_BITS) | limb as u128),
+        )
+    }
+
+    /// Computes self * factor + addend in place.
+    fn mul_add_small(&mut self, factor: u32, addend: u32) {
+        let mut carry = addend as u64;
+        for limb in self.limbs.iter_mut() {
+            let value = *limb as u64 * factor as u64 + carry;
+            *limb = value as u32;
+            carry = value >> LIMB_BITS;
+        }
+        if carry != 0 {
+            self.limbs.push(carry as u32);
+        }
+        *self = BigUint::from_limbs(std::mem::take(&mut self.limbs));
+    }
+
+    /// Builds a number from its digits in the given radix, most significant first.
+    pub(crate) fn from_digits(digits: impl IntoIterator<Item = u32>, radix: u32) -> BigUint {
+        let mut res = BigUint::default();
+        // Digits are collected into chunks fitting into a single limb
+        let (mut chunk_value, mut chunk_factor) = (0, 1);
+        for digit in digits {
+            if chunk_factor > u32::MAX / radix {
+                res.mul_add_small(chunk_factor, chunk_value);
+                (chunk_value, chunk_factor) = (0, 1);
+            };
+            chunk_value = chunk_value * radix + digit;
+            chunk_factor *= radix;
+        }
+        res.mul_add_small(chunk_factor, chunk_value);
+        res
+    }
+
+    /// Returns the digits in the given radix, most significant first. Zero has the single digit 0.
+    pub(crate) fn to_digits(&self, radix: u32) -> Vec<u8> {
+        let mut digits = Vec::new();
+        let mut rest = self.clone();
+        loop {
+            digits.push(rest.div_rem_small(radix) as u8);
+            if rest.is_zero() {
+                break;
+            };
+        }
+        digits.reverse();
+        digits
+    }
+
+    /// Divides self by divisor in place, returning the remainder.
+    fn div_rem_small(&mut self, divisor: u32) -> u32 {
+        if divisor == 0 {
+            panic!("attempt to divide by zero")
+        };
+        let mut remainder = 0u64;
+        for limb in self.limbs.iter_mut().rev() {
+            let value = (remainder << LIMB_BITS) | *limb as u64;
+            *limb = (value / divisor as u64) as u32;
+            remainder = value % divisor as u64;
+        }
+        *self = BigUint::from_limbs(std::mem::take(&mut self.limbs));
+        remainder as u32
+    }
+
+    /// The largest integer whose `n`-th power doesn't exceed self.
+    pub(crate) fn nth_root(&self, n: u32) -> BigUint {
+        assert!(n > 0, "attempt to take a root of degree zero");
+        if n == 1 || self.bits() <= 1 {
+            return self.clone();
+        };
+        if n as u64 >= self.bits() {
+            // 2^n is already too large
+            return BigUint::one();
+        };
+
+        // Newton's method, starting above the root and decreasing monotonically towards it
+        let degree = BigUint::from(n);
+        let mut root = BigUint::one() << self.bits().div_ceil(n as u64) as u32;
+        loop {
+            let next = (root.clone() * BigUint::from(n - 1)
+                + self.clone() / root.clone().pow(n - 1))
+                / degree.clone();
+            if next >= root {
+                return root;
+            };
+            root = next;
+        }
+    }
+
+    pub fn div_rem(&self, divisor: &BigUint) -> (BigUint, BigUint) {
+        if divisor.is_zero() {
+            panic!("attempt to divide by zero")
+        };
+        if self < divisor {
+            return (BigUint::default(), self.clone());
+        };
+        if divisor.limbs.len() == 1 {
+            let mut quotient = self.clone();
+            let remainder = quotient.div_rem_small(divisor.limbs[0]);
+            return (quotient, BigUint::from(remainder));
+        };
+
+        // Knuth's algorithm D (The Art of Computer Programming, vol. 2, 4.3.1)
+        let base = 1u64 << LIMB_BITS;
+        let shift = divisor.limbs.last().unwrap().leading_zeros();
+        let v = (divisor.clone() << shift).limbs;
+        let mut u = (self.clone() << shift).limbs;
+        u.resize(self.limbs.len() + 1, 0);
+
+        let n = v.len();
+        let m = u.len() - 1;
+        let mut quotient = vec![0u32; m - n + 1];
+
+        for j in (0..=m - n).rev() {
+            let numerator = ((u[j + n] as u64) << LIMB_BITS) | u[j + n - 1] as u64;
+            let mut q_hat = numerator / v[n - 1] as u64;
+            let mut r_hat = numerator % v[n - 1] as u64;
+
+            while q_hat >= base
+                || q_hat * v[n - 2] as u64 > ((r_hat << LIMB_BITS) | u[j + n - 2] as u64)
+            {
+                q_hat -= 1;
+                r_hat += v[n - 1] as u64;
+                if r_hat >= base {
+                    break;
+                };
+            }
+
+            // Multiply and subtract
+            let mut borrow: i64 = 0;
+            for i in 0..n {
+                let product = q_hat * v[i] as u64;
+                let t = u[i + j] as i64 - borrow - (product & u32::MAX as u64) as i64;
+                u[i + j] = t as u32;
+                borrow = (product >> LIMB_BITS) as i64 - (t >> LIMB_BITS);
+            }
+            let t = u[j + n] as i64 - borrow;
+            u[j + n] = t as u32;
+
+            // The estimate was one too large, add back
+            if t < 0 {
+                q_hat -= 1;
+                let mut carry = 0u64;
+                for i in 0..n {
+                    let sum = u[i + j] as u64 + v[i] as u64 + carry;
+                    u[i + j] = sum as u32;
+                    carry = sum >> LIMB_BITS;
+                }
+                u[j + n] = u[j + n].wrapping_add(carry as u32);
+            }
+
+            quotient[j] = q_hat as u32;
+        }
+
+        u.truncate(n);
+        (
+            BigUint::from_limbs(quotient),
+            BigUint::from_limbs(u) >> shift,
+        )
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+macro_rules! impl_from_unsigned {
+    ($($int:ty),*) => {$(
+        impl From<$int> for BigUint {
+            fn from(value: $int) -> Self {
+                BigUint::from_u128(value as u128)
+            }
+        }
+
+        impl From<$int> for BigInt {
+            fn from(value: $int) -> Self {
+                BigInt::from_unsigned(BigUint::from(value))
+            }
+        }
+
+        impl TryFrom<BigInt> for $int {
+            type Error = TryFromBigIntError;
+
+            fn try_from(value: BigInt) -> Result<Self, Self::Error> {
+                if value.negative {
+                    return Err(TryFromBigIntError(()));
+                };
+                value
+                    .magnitude
+                    .to_u128()
+                    .and_then(|value| <$int>::try_from(value).ok())
+                    .ok_or(TryFromBigIntError(()))
+            }
+        }
+    )*};
+}
+
+impl_from_unsigned!(u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_from_signed {
+    ($($int:ty),*) => {$(
+        impl From<$int> for BigInt {
+            fn from(value: $int) -> Self {
+                let magnitude = BigUint::from(value.unsigned_abs());
+                if value < 0 {
+                    -BigInt::from_unsigned(magnitude)
+                } else {
+                    BigInt::from_unsigned(magnitude)
+                }
+            }
+        }
+
+        impl TryFrom<BigInt> for $int {
+            type Error = TryFromBigIntError;
+
+            fn try_from(value: BigInt) -> Result<Self, Self::Error> {
+                let magnitude = value.magnitude.to_u128().ok_or(TryFromBigIntError(()))?;
+                let value = if value.negative {
+                    0i128.checked_sub_unsigned(magnitude)
+                } else {
+                    0i128.checked_add_unsigned(magnitude)
+                };
+                value
+                    .and_then(|value| <$int>::try_from(value).ok())
+                    .ok_or(TryFromBigIntError(()))
+            }
+        }
+
+        impl From<Ratio<$int>> for BigRational {
+            fn from(value: Ratio<$int>) -> Self {
+                Ratio::new_unchecked(value.numerator().into(), value.denominator().into())
+            }
+        }
+
+        impl TryFrom<BigRational> for Ratio<$int> {
+            type Error = TryFromBigIntError;
+
+            fn try_from(value: BigRational) -> Result<Self, Self::Error> {
+                Ok(Ratio::new_unchecked(
+                    value.numerator().try_into()?,
+                    value.denominator().try_into()?,
+                ))
+            }
+        }
+    )*};
+}
+
+impl_from_signed!(i8, i16, i32, i64, i128, isize);
+
+impl Add for BigUint {
+    type Output = BigUint;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let (mut long, short) = if self.limbs.len() >= rhs.limbs.len() {
+            (self.limbs, rhs.limbs)
+        } else {
+            (rhs.limbs, self.limbs)
+        };
+        let mut carry = 0u64;
+        for (i, limb) in long.iter_mut().enumerate() {
+            if i >= short.len() && carry == 0 {
+                break;
+            };
+            let sum = *limb as u64 + *short.get(i).unwrap_or(&0) as u64 + carry;
+            *limb = sum as u32;
+            carry = sum >> LIMB_BITS;
+        }
+        if carry != 0 {
+            long.push(carry as u32);
+        }
+        BigUint { limbs: long }
+    }
+}
+
+impl Sub for BigUint {
+    type Output = BigUint;
+
+    /// Panics when rhs > self.
+    fn sub(self, rhs: Self) -> Self::Output {
+        if self < rhs {
+            panic!("attempt to subtract with overflow")
+        };
+        let mut limbs = self.limbs;
+        let mut borrow = 0i64;
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            if i >= rhs.limbs.len() && borrow == 0 {
+                break;
+            };
+            let difference = *limb as i64 - *rhs.limbs.get(i).unwrap_or(&0) as i64 - borrow;
+            *limb = difference as u32;
+            borrow = if difference < 0 { 1 } else { 0 };
+        }
+        BigUint::from_limbs(limbs)
+    }
+}
+
+impl Mul for BigUint {
+    type Output = BigUint;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        if self.is_zero() || rhs.is_zero() {
+            return BigUint::default();
+        };
+        let mut limbs = vec![0u32; self.limbs.len() + rhs.limbs.len()];
+        for (i, &a) in self.limbs.iter().enumerate() {
+            let mut carry = 0u64;
+            for (j, &b) in rhs.limbs.iter().enumerate() {
+                let value = a as u64 * b as u64 + limbs[i + j] as u64 + carry;
+                limbs[i + j] = value as u32;
+                carry = value >> LIMB_BITS;
+            }
+            limbs[i + rhs.limbs.len()] = carry as u32;
+        }
+        BigUint::from_limbs(limbs)
+    }
+}
+
+impl Div for BigUint {
+    type Output = BigUint;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.div_rem(&rhs).0
+    }
+}
+
+impl Rem for BigUint {
+    type Output = BigUint;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.div_rem(&rhs).1
+    }
+}
+
+impl Shl<u32> for BigUint {
+    type Output = BigUint;
+
+    fn shl(self, rhs: u32) -> Self::Output {
+        if self.is_zero() {
+            return self;
+        };
+        let limb_shift = (rhs / LIMB_BITS) as usize;
+        let bit_shift = rhs % LIMB_BITS;
+        let mut limbs = vec![0u32; limb_shift];
+        limbs.reserve(self.limbs.len() + 1);
+        if bit_shift == 0 {
+            limbs.extend_from_slice(&self.limbs);
+        } else {
+            let mut carry = 0;
+            for limb in self.limbs {
+                limbs.push((limb << bit_shift) | carry);
+                carry = limb >> (LIMB_BITS - bit_shift);
+            }
+            limbs.push(carry);
+        }
+        BigUint::from_limbs(limbs)
+    }
+}
+
+impl Shr<u32> for BigUint {
+    type Output = BigUint;
+
+    fn shr(self, rhs: u32) -> Self::Output {
+        let limb_shift = (rhs / LIMB_BITS) as usize;
+        let bit_shift = rhs % LIMB_BITS;
+        if limb_shift >= self.limbs.len() {
+            return BigUint::default();
+        };
+        let mut limbs = self.limbs[limb_shift..].to_vec();
+        if bit_shift != 0 {
+            for i in 0..limbs.len() {
+                let high = limbs
+                    .get(i + 1)
+                    .map_or(0, |limb| limb << (LIMB_BITS - bit_shift));
+                limbs[i] = (limbs[i] >> bit_shift) | high;
+            }
+        }
+        BigUint::from_limbs(limbs)
+    }
+}
+
+impl sealed::Sealed for BigUint {}
+
+impl Unsigned for BigUint {
+    fn zero() -> Self {
+        BigUint::default()
+    }
+
+    fn one() -> Self {
+        BigUint::from(1u8)
+    }
+
+    fn from_u8(value: u8) -> Self {
+        BigUint::from(value)
+    }
+
+    fn is_even(&self) -> bool {
+        self.limbs.first().is_none_or(|limb| limb % 2 == 0)
+    }
+
+    fn pow(self, mut exp: u32) -> Self {
+        let mut base = self;
+        let mut res = BigUint::from(1u8);
+        while exp > 0 {
+            if exp % 2 == 1 {
+                res = res * base.clone();
+            }
+            exp /= 2;
+            if exp > 0 {
+                base = base.clone() * base;
+            }
+        }
+        res
+    }
+
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        Some(self.clone() + rhs.clone())
+    }
+
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        Some(self.clone() * rhs.clone())
+    }
+}
+
+impl Display for BigUint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut chunks = Vec::new();
+        let mut rest = self.clone();
+        while !rest.is_zero() {
+            chunks.push(rest.div_rem_small(DECIMAL_BASE));
+        }
+        let mut digits = chunks.pop().unwrap_or(0).to_string();
+        for chunk in chunks.iter().rev() {
+            digits.push_str(&format!("{chunk:0width$}", width = DECIMAL_BASE_DIGITS));
+        }
+        f.pad_integral(true, "", &digits)
+    }
+}
+
+impl FromStr for BigUint {
+    type Err = ParseBigIntError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if value.is_empty() || !value.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Err(ParseBigIntError(()));
+        };
+        let mut res = BigUint::default();
+        for chunk in value.as_bytes().chunks(DECIMAL_BASE_DIGITS) {
+            let chunk_value = chunk
+                .iter()
+                .fold(0, |acc, digit| acc * 10 + (digit - b'0') as u32);
+            res.mul_add_small(10u32.pow(chunk.len() as u32), chunk_value);
+        }
+        Ok(res)
+    }
+}
+
+impl BigInt {
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_zero()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn magnitude(&self) -> &BigUint {
+        &self.magnitude
+    }
+
+    fn from_sign_magnitude(negative: bool, magnitude: BigUint) -> BigInt {
+        BigInt {
+            negative: negative && !magnitude.is_zero(),
+            magnitude,
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> Self::Output {
+        BigInt::from_sign_magnitude(!self.negative, self.magnitude)
+    }
+}
+
+impl Add for BigInt {
+    type Output = BigInt;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        if self.negative == rhs.negative {
+            return BigInt::from_sign_magnitude(self.negative, self.magnitude + rhs.magnitude);
+        };
+        if self.magnitude >= rhs.magnitude {
+            BigInt::from_sign_magnitude(self.negative, self.magnitude - rhs.magnitude)
+        } else {
+            BigInt::from_sign_magnitude(rhs.negative, rhs.magnitude - self.magnitude)
+        }
+    }
+}
+
+impl Sub for BigInt {
+    type Output = BigInt;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + (-rhs)
+    }
+}
+
+impl Mul for BigInt {
+    type Output = BigInt;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        BigInt::from_sign_magnitude(
+            self.negative != rhs.negative,
+            self.magnitude * rhs.magnitude,
+        )
+    }
+}
+
+impl Div for BigInt {
+    type Output = BigInt;
+
+    /// Rounds towards zero, like primitive integer division.
+    fn div(self, rhs: Self) -> Self::Output {
+        BigInt::from_sign_magnitude(
+            self.negative != rhs.negative,
+            self.magnitude / rhs.magnitude,
+        )
+    }
+}
+
+impl Rem for BigInt {
+    type Output = BigInt;
+
+    /// Has the sign of self, like primitive integer remainder.
+    fn rem(self, rhs: Self) -> Self::Output {
+        BigInt::from_sign_magnitude(self.negative, self.magnitude % rhs.magnitude)
+    }
+}
+
+impl sealed::Sealed for BigInt {}
+
+impl Integer for BigInt {
+    type Unsigned = BigUint;
+
+    fn zero() -> Self {
+        BigInt::default()
+    }
+
+    fn one() -> Self {
+        BigInt::from(1u8)
+    }
+
+    fn unsigned_abs(&self) -> Self::Unsigned {
+        self.magnitude.clone()
+    }
+
+    fn from_unsigned(value: Self::Unsigned) -> Self {
+        BigInt::from_sign_magnitude(false, value)
+    }
+
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        Some(self.clone() + rhs.clone())
+    }
+
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        Some(self.clone() - rhs.clone())
+    }
+
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        Some(self.clone() * rhs.clone())
+    }
+
+    fn checked_neg(&self) -> Option<Self> {
+        Some(-self.clone())
+    }
+
+    fn bounds() -> Option<(Self, Self)> {
+        None
+    }
+
+    fn to_big_int(&self) -> BigInt {
+        self.clone()
+    }
+
+    fn from_big_int(value: &BigInt) -> Option<Self> {
+        Some(value.clone())
+    }
+}
+
+impl Display for BigInt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.pad_integral(!self.negative, "", &self.magnitude.to_string())
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = ParseBigIntError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.strip_prefix('-') {
+            Some(magnitude) => Ok(-BigInt::from_unsigned(magnitude.parse()?)),
+            None => Ok(BigInt::from_unsigned(value.parse()?)),
+        }
+    }
+}
+
+impl Display for ParseBigIntError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid big integer literal")
+    }
+}
+
+impl Error for ParseBigIntError {}
+
+impl Display for TryFromBigIntError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "big integer out of range of the target type")
+    }
+}
+
+impl Error for TryFromBigIntError {}
@@ -0,0 +1,320 @@
+//! Positional (e.g. decimal) expansion of fractions.
+
+use std::cmp::Ordering;
+
+use crate::{Integer, Ratio, Unsigned};
+
+impl<T: Integer> Ratio<T> {
+    /// Returns the exact decimal representation, with the repeating part in parentheses.
+    ///
+    /// Both the digits before the repeating part and the repeating part itself are as short as possible,
+    /// and the result parses back into the same rational.
+    ///
+    /// The repeating part can be up to `q - 1` digits long, so time and memory grow linearly with the
+    /// denominator: `1/999999937` expands to a string of hundreds of millions of characters. Use
+    /// `{:.N}` formatting when a bounded number of digits is enough.
+    /// ```
+    /// # use rational::Rational;
+    /// let a = Rational::new(-25, 7);
+    ///
+    /// assert_eq!(a.to_repeating_decimal(), "-3.(571428)");
+    /// assert_eq!(a.to_repeating_decimal().parse(), Ok(a));
+    /// assert_eq!(Rational::new(1, 6).to_repeating_decimal(), "0.1(6)");
+    /// ```
+    pub fn to_repeating_decimal(&self) -> String {
+        format!("{self:#}")
+    }
+
+    /// Returns the exact decimal representation like [Ratio::to_repeating_decimal],
+    /// with the repeating part written in the given style.
+    ///
+    /// Every style parses back into the same rational.
+    ///
+    /// Takes time and memory linear in the denominator, see [Ratio::to_repeating_decimal].
+    /// ```
+    /// # use rational::{Rational, RepeatingStyle};
+    /// let a = Rational::new(1, 6);
+    ///
+    /// assert_eq!(a.to_repeating_decimal_with(RepeatingStyle::Brackets), "0.1[6]");
+    /// assert_eq!(a.to_repeating_decimal_with(RepeatingStyle::Overline), "0.16\u{305}");
+    /// assert_eq!(a.to_repeating_decimal_with(RepeatingStyle::Ellipsis), "0.166...");
+    /// ```
+    pub fn to_repeating_decimal_with(&self, style: RepeatingStyle) -> String {
+        let expansion = expand(self.p.unsigned_abs(), self.q.unsigned_abs(), 10);
+
+        let sign = if self.p < T::zero() { "-" } else { "" };
+        let integral = expansion.integral.to_string();
+        format!("{sign}{}", expansion.to_string_with(integral, 10, style))
+    }
+
+    /// Returns an iterator over the decimal digits after the point, ignoring the sign.
+    ///
+    /// The iterator yields the digits before the repeating part, then a single period, and stops.
+    /// ```
+    /// # use rational::{Digit, Rational};
+    /// let digits: Vec<_> = Rational::new(-7, 6).decimal_digits().collect();
+    ///
+    /// assert_eq!(digits, [Digit::NonRepeating(1), Digit::Repeating(6)]);
+    /// ```
+    pub fn decimal_digits(&self) -> Digits<T::Unsigned> {
+        let d = self.q.unsigned_abs();
+        Digits::new(self.p.unsigned_abs() % d.clone(), d, 10)
+    }
+
+    /// Returns the exact representation in the given radix, in the notation of [Ratio::to_repeating_decimal].
+    ///
+    /// Digits above 9 are lowercase letters. The result parses back with [Ratio::from_str_radix].
+    /// Like [Ratio::to_repeating_decimal], it takes time and memory linear in the denominator.
+    ///
+    /// Panics when the radix is not in the range from 2 to 36.
+    /// ```
+    /// # use rational::Rational;
+    /// assert_eq!(Rational::new(1, 3).to_string_radix(2), "0.(01)");
+    /// assert_eq!(Rational::new(-255, 16).to_string_radix(16), "-f.f");
+    /// ```
+    pub fn to_string_radix(&self, radix: u32) -> String {
+        assert!(
+            (2..=36).contains(&radix),
+            "Radix must be in the range from 2 to 36"
+        );
+        let n = self.p.to_big_int().magnitude().clone();
+        let d = self.q.to_big_int().magnitude().clone();
+        let expansion = expand(n, d, radix as u8);
+
+        let sign = if self.p < T::zero() { "-" } else { "" };
+        let integral = digits_to_string(&expansion.integral.to_digits(radix), radix as u8);
+        format!(
+            "{sign}{}",
+            expansion.to_string_with(integral, radix as u8, RepeatingStyle::Parentheses)
+        )
+    }
+}
+
+/// Notation of the repeating part of a decimal, see [Ratio::to_repeating_decimal_with].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum RepeatingStyle {
+    /// The repeating part in parentheses, e.g. `0.1(6)`.
+    #[default]
+    Parentheses,
+    /// The repeating part in brackets, e.g. `0.1[6]`.
+    Brackets,
+    /// A combining overline (U+0305) after each repeating digit, e.g. `0.16̅`.
+    Overline,
+    /// The repeating part written twice and followed by an ellipsis, e.g. `0.166...`.
+    Ellipsis,
+}
+
+/// Expansion of a non-negative fraction: the integral part,
+/// the digits before the repeating part and the repeating part itself.
+pub(crate) struct Expansion<U> {
+    pub(crate) integral: U,
+    pub(crate) prefix: Vec<u8>,
+    pub(crate) period: Vec<u8>,
+}
+
+impl<U> Expansion<U> {
+    /// Writes the expansion as `integral.prefix(period)` or in another style, leaving out the empty parts.
+    pub(crate) fn to_string_with(
+        &self,
+        mut integral: String,
+        radix: u8,
+        style: RepeatingStyle,
+    ) -> String {
+        if !self.prefix.is_empty() || !self.period.is_empty() {
+            integral.push('.');
+            integral.push_str(&digits_to_string(&self.prefix, radix));
+        };
+        if self.period.is_empty() {
+            return integral;
+        };
+
+        let period = digits_to_string(&self.period, radix);
+        match style {
+            RepeatingStyle::Parentheses => integral.push_str(&format!("({period})")),
+            RepeatingStyle::Brackets => integral.push_str(&format!("[{period}]")),
+            RepeatingStyle::Overline => {
+                for digit in period.chars() {
+                    integral.push(digit);
+                    integral.push('\u{305}');
+                }
+            }
+            RepeatingStyle::Ellipsis => integral.push_str(&format!("{period}{period}...")),
+        };
+        integral
+    }
+}
+
+/// Expands the reduced fraction n / d in the given radix.
+pub(crate) fn expand<U: Unsigned>(n: U, d: U, radix: u8) -> Expansion<U> {
+    let integral = n.clone() / d.clone();
+
+    let mut prefix = Vec::new();
+    let mut period = Vec::new();
+    for digit in Digits::new(n % d.clone(), d, radix) {
+        match digit {
+            Digit::NonRepeating(digit) => prefix.push(digit),
+            Digit::Repeating(digit) => period.push(digit),
+        }
+    }
+
+    Expansion {
+        integral,
+        prefix,
+        period,
+    }
+}
+
+/// A digit after the point, marked by whether it belongs to the repeating part.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Digit {
+    NonRepeating(u8),
+    Repeating(u8),
+}
+
+/// Iterator over the digits after the point of a rational, see [Ratio::decimal_digits](crate::Rational::decimal_digits).
+///
+/// Yields the digits before the repeating part, then exactly one period, and stops.
+#[derive(Debug, Clone)]
+pub struct Digits<U> {
+    remainder: U,
+    d: U,
+    radix: u8,
+    prefix_length: usize,
+    period_start: Option<U>,
+}
+
+impl<U: Unsigned> Digits<U> {
+    /// Digits of the proper fraction remainder / d, with d reduced against the remainder.
+    pub(crate) fn new(remainder: U, d: U, radix: u8) -> Digits<U> {
+        Digits {
+            prefix_length: pre_period_length(d.clone(), radix),
+            remainder,
+            d,
+            radix,
+            period_start: None,
+        }
+    }
+}
+
+impl<U: Unsigned> Iterator for Digits<U> {
+    type Item = Digit;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remainder == U::zero() {
+            return None;
+        };
+
+        if self.prefix_length > 0 {
+            self.prefix_length -= 1;
+            let digit;
+            (digit, self.remainder) = next_digit(&self.remainder, &self.d, self.radix);
+            return Some(Digit::NonRepeating(digit));
+        };
+
+        // After the prefix the remainders are purely periodic,
+        // so the period ends as soon as the first remainder comes back
+        match &self.period_start {
+            Some(start) if start == &self.remainder => return None,
+            Some(_) => (),
+            None => self.period_start = Some(self.remainder.clone()),
+        };
+        let digit;
+        (digit, self.remainder) = next_digit(&self.remainder, &self.d, self.radix);
+        Some(Digit::Repeating(digit))
+    }
+}
+
+/// Expands the fraction n / d in the given radix up to `precision` digits after the point,
+/// rounding the last digit half to even.
+pub(crate) fn expand_rounded<U: Unsigned>(n: U, d: U, radix: u8, precision: usize) -> (U, Vec<u8>) {
+    let mut integral = n.clone() / d.clone();
+    let mut remainder = n % d.clone();
+
+    let mut digits = Vec::with_capacity(precision);
+    for _ in 0..precision {
+        let digit;
+        (digit, remainder) = next_digit(&remainder, &d, radix);
+        digits.push(digit);
+    }
+
+    let last_is_odd = match digits.last() {
+        Some(digit) => digit % 2 == 1,
+        None => !integral.is_even(),
+    };
+    let round_up = match (remainder.clone() + remainder).cmp(&d) {
+        Ordering::Greater => true,
+        Ordering::Equal => last_is_odd,
+        Ordering::Less => false,
+    };
+
+    if round_up {
+        let mut carry = true;
+        for digit in digits.iter_mut().rev() {
+            *digit += 1;
+            if *digit < radix {
+                carry = false;
+                break;
+            };
+            *digit = 0;
+        }
+        if carry {
+            integral = integral + U::one();
+        };
+    };
+
+    (integral, digits)
+}
+
+/// One step of the long division: returns (remainder * radix) / d and (remainder * radix) % d.
+///
+/// The product is calculated by repeated addition modulo d, so nothing bigger than 2 * d is ever stored.
+pub(crate) fn next_digit<U: Unsigned>(remainder: &U, d: &U, radix: u8) -> (u8, U) {
+    let mut digit = 0;
+    let mut product = U::zero();
+    for _ in 0..radix {
+        product = product + remainder.clone();
+        if &product >= d {
+            product = product - d.clone();
+            digit += 1;
+        };
+    }
+    (digit, product)
+}
+
+/// Number of digits before the repeating part of a reduced fraction with denominator d.
+fn pre_period_length<U: Unsigned>(d: U, radix: u8) -> usize {
+    let mut radix_rest = radix;
+    let mut length = 0;
+
+    for prime in 2..=radix {
+        if !radix_rest.is_multiple_of(prime) {
+            continue;
+        };
+        let mut radix_multiplicity = 0;
+        while radix_rest.is_multiple_of(prime) {
+            radix_rest /= prime;
+            radix_multiplicity += 1;
+        }
+
+        let mut d_multiplicity: usize = 0;
+        let mut d_rest = d.clone();
+        let prime = U::from_u8(prime);
+        while d_rest.clone() % prime.clone() == U::zero() {
+            d_rest = d_rest / prime.clone();
+            d_multiplicity += 1;
+        }
+
+        length = length.max(d_multiplicity.div_ceil(radix_multiplicity));
+    }
+
+    length
+}
+
+pub(crate) fn digits_to_string(digits: &[u8], radix: u8) -> String {
+    digits
+        .iter()
+        .map(|&digit| {
+            char::from_digit(digit as u32, radix as u32).expect("Digit is less than the radix")
+        })
+        .collect()
+}
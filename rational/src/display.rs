@@ -0,0 +1,142 @@
+//! Formatting rationals as fractions, decimals, percentages and ratios.
+
+use std::fmt::{self, Display, Formatter};
+
+use crate::decimal::{digits_to_string, expand, expand_rounded, RepeatingStyle};
+use crate::{BigInt, Integer, Ratio, Unsigned};
+
+/// Formats the rational as a fraction, or as a decimal depending on the formatter flags:
+///
+/// * `{}` prints the reduced fraction `p/q`, or just `p` when the rational is an integer,
+/// * `{:#}` prints the exact decimal representation, with the repeating part in parentheses,
+///   in the same notation [FromStr](std::str::FromStr) accepts,
+/// * `{:.N}` prints the decimal representation rounded half to even to `N` digits after the point.
+///
+/// The repeating part printed by `{:#}` can be up to `q - 1` digits long, so it takes time and memory
+/// linear in the denominator, e.g. hundreds of millions of characters for `1/999999937`.
+///
+/// ```
+/// # use rational::Rational;
+/// let a = Rational::new(-7, 6);
+///
+/// assert_eq!(format!("{a}"), "-7/6");
+/// assert_eq!(format!("{a:#}"), "-1.1(6)");
+/// assert_eq!(format!("{a:.3}"), "-1.167");
+/// assert_eq!(format!("{a:>8}"), "    -7/6");
+/// ```
+impl<T: Integer> Display for Ratio<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (n, d) = (self.p.unsigned_abs(), self.q.unsigned_abs());
+
+        let body = if f.precision().is_some() || f.alternate() {
+            decimal_body(n, d, f.precision())
+        } else if d == T::Unsigned::one() {
+            n.to_string()
+        } else {
+            format!("{n}/{d}")
+        };
+
+        f.pad_integral(self.p >= T::zero(), "", &body)
+    }
+}
+
+impl<T: Integer> Ratio<T> {
+    /// Returns an object formatting the rational as a percentage.
+    ///
+    /// The percentage is printed as an exact decimal, with the repeating part in parentheses,
+    /// or rounded half to even to `N` digits after the point with `{:.N}`. Like `{:#}`, the exact
+    /// decimal takes time and memory linear in the denominator.
+    /// ```
+    /// # use rational::Rational;
+    /// let a = Rational::new(1, 3);
+    ///
+    /// assert_eq!(a.display_percent().to_string(), "33.(3)%");
+    /// assert_eq!(format!("{:.1}", a.display_percent()), "33.3%");
+    /// ```
+    pub fn display_percent(&self) -> DisplayScaled<'_, T> {
+        DisplayScaled {
+            value: self,
+            scale: 100,
+            symbol: '%',
+        }
+    }
+
+    /// Returns an object formatting the rational as a per-mille value, like [Ratio::display_percent].
+    /// ```
+    /// # use rational::Rational;
+    /// assert_eq!(Rational::new(7, 1000).display_per_mille().to_string(), "7‰");
+    /// ```
+    pub fn display_per_mille(&self) -> DisplayScaled<'_, T> {
+        DisplayScaled {
+            value: self,
+            scale: 1000,
+            symbol: '‰',
+        }
+    }
+
+    /// Returns an object formatting the rational as a ratio `p:q` of its numerator and denominator.
+    /// ```
+    /// # use rational::Rational;
+    /// assert_eq!(Rational::new(32, 18).display_ratio().to_string(), "16:9");
+    /// ```
+    pub fn display_ratio(&self) -> DisplayRatio<'_, T> {
+        DisplayRatio { value: self }
+    }
+}
+
+/// Formats a rational multiplied by a scale and followed by a symbol,
+/// see [Ratio::display_percent] and [Ratio::display_per_mille].
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayScaled<'a, T> {
+    value: &'a Ratio<T>,
+    scale: u32,
+    symbol: char,
+}
+
+impl<T: Integer> Display for DisplayScaled<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        // Scaling may overflow `T`, so it is done with arbitrary precision
+        let scaled = self.value.to_big() * Ratio::from(BigInt::from(self.scale));
+        let (n, d) = (scaled.p.unsigned_abs(), scaled.q.unsigned_abs());
+
+        let mut body = decimal_body(n, d, f.precision());
+        body.push(self.symbol);
+        f.pad_integral(self.value.p >= T::zero(), "", &body)
+    }
+}
+
+/// Formats a rational as a ratio, see [Ratio::display_ratio].
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayRatio<'a, T> {
+    value: &'a Ratio<T>,
+}
+
+impl<T: Integer> Display for DisplayRatio<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (n, d) = (self.value.p.unsigned_abs(), self.value.q.unsigned_abs());
+        f.pad_integral(self.value.p >= T::zero(), "", &format!("{n}:{d}"))
+    }
+}
+
+/// Writes the fraction n / d as a decimal, rounded half to even to `precision` digits after the point,
+/// or exactly, with the repeating part in parentheses, when there is no precision.
+fn decimal_body<U: Unsigned>(n: U, d: U, precision: Option<usize>) -> String {
+    match precision {
+        Some(precision) => {
+            let (integral, digits) = expand_rounded(n, d, 10, precision);
+            if digits.is_empty() {
+                integral.to_string()
+            } else {
+                format!("{integral}.{}", digits_to_string(&digits, 10))
+            }
+        }
+        None => {
+            let expansion = expand(n, d, 10);
+            expansion.to_string_with(
+                expansion.integral.to_string(),
+                10,
+                RepeatingStyle::Parentheses,
+            )
+        }
+    }
+}
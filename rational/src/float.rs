@@ -0,0 +1,276 @@
+//! Conversions between rationals and floating-point numbers.
+
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use crate::approximation::best_with_denominator;
+use crate::{BigInt, BigRational, BigUint, Integer, Ratio, Unsigned};
+
+/// The error returned when a floating-point number can't be converted into a [Ratio].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TryFromFloatError(FloatErrorCause);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FloatErrorCause {
+    NotFinite,
+    OutOfRange,
+}
+
+const MANTISSA_BITS: u32 = 52;
+const EXPONENT_MASK: u64 = 0x7ff;
+/// Exponent of the least significant mantissa bit for subnormal numbers.
+const MIN_EXPONENT: i32 = -1074;
+
+/// Splits a finite float into its sign, an odd (or zero) mantissa and a binary exponent.
+fn decompose(value: f64) -> Option<(bool, u64, i32)> {
+    let bits = value.to_bits();
+    let negative = bits >> 63 == 1;
+    let biased = (bits >> MANTISSA_BITS) & EXPONENT_MASK;
+    let fraction = bits & ((1 << MANTISSA_BITS) - 1);
+
+    let (mantissa, exponent) = match biased {
+        EXPONENT_MASK => return None,
+        0 => (fraction, MIN_EXPONENT),
+        _ => (
+            fraction | 1 << MANTISSA_BITS,
+            biased as i32 + MIN_EXPONENT - 1,
+        ),
+    };
+    if mantissa == 0 {
+        return Some((false, 0, 0));
+    };
+    let shift = mantissa.trailing_zeros();
+    Some((negative, mantissa >> shift, exponent + shift as i32))
+}
+
+/// The exact value of a finite float.
+fn to_exact(value: f64) -> Result<BigRational, TryFromFloatError> {
+    let (negative, mantissa, exponent) =
+        decompose(value).ok_or(TryFromFloatError(FloatErrorCause::NotFinite))?;
+
+    let mantissa = BigUint::from(mantissa);
+    let (p, q) = if exponent >= 0 {
+        (mantissa << exponent as u32, BigUint::one())
+    } else {
+        (mantissa, BigUint::one() << exponent.unsigned_abs())
+    };
+    let p = BigInt::from_unsigned(p);
+    let p = if negative { -p } else { p };
+    Ok(Ratio::new_unchecked(p, BigInt::from_unsigned(q)))
+}
+
+impl<T: Integer> Ratio<T> {
+    /// Finds the fraction closest to a float with a denominator of at most `max_denominator`.
+    ///
+    /// Equivalent to converting the float exactly and calling
+    /// [limit_denominator](Ratio::limit_denominator) on the result, but the exact value doesn't
+    /// need to fit into `T`.
+    ///
+    /// ```
+    /// # use rational::*;
+    /// assert_eq!(Ratio::<i64>::approximate(0.1, 1000), Ok(Ratio::new(1, 10)));
+    /// assert_eq!(
+    ///     Ratio::<i32>::approximate(std::f64::consts::PI, 1000),
+    ///     Ok(Ratio::new(355, 113))
+    /// );
+    /// assert!(Ratio::<i8>::approximate(1000.0, 10).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Fails when the float is NaN or infinite, or when the approximation doesn't fit into `T`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_denominator` is less than 1.
+    pub fn approximate(value: f64, max_denominator: T) -> Result<Self, TryFromFloatError> {
+        assert!(
+            max_denominator >= T::one(),
+            "max_denominator must be at least 1"
+        );
+        let exact = to_exact(value)?;
+        let approximation = best_with_denominator(&exact, &max_denominator.to_big_int());
+        Ratio::from_big(approximation).ok_or(TryFromFloatError(FloatErrorCause::OutOfRange))
+    }
+
+    /// Converts into the nearest `f64`, rounding half to even.
+    ///
+    /// The result is correctly rounded for every value, including subnormal results. Values too
+    /// large for an `f64` become infinite, and values too small become zero.
+    ///
+    /// ```
+    /// # use rational::*;
+    /// assert_eq!(Ratio::<i64>::new(1, 10).to_f64(), 0.1);
+    /// assert_eq!(Ratio::<i64>::new(-7, 2).to_f64(), -3.5);
+    /// assert_eq!(Ratio::<i64>::new((1 << 53) + 1, 1).to_f64(), 9007199254740992.0);
+    /// ```
+    pub fn to_f64(&self) -> f64 {
+        f64::from_bits(round_to_float(&self.to_big(), &F64))
+    }
+
+    /// Converts into the nearest `f32`, rounding half to even.
+    ///
+    /// Rounds directly rather than through an `f64`, so there is no double rounding.
+    ///
+    /// ```
+    /// # use rational::*;
+    /// assert_eq!(Ratio::<i64>::new(1, 3).to_f32(), 1.0 / 3.0);
+    /// assert_eq!(Ratio::<i64>::new(1 << 40, 1).to_f32(), 1099511627776.0);
+    /// ```
+    pub fn to_f32(&self) -> f32 {
+        f32::from_bits(round_to_float(&self.to_big(), &F32) as u32)
+    }
+}
+
+/// The layout of an IEEE-754 binary format.
+struct Format {
+    /// Significand precision, including the implicit bit.
+    precision: u32,
+    min_exponent: i64,
+    max_exponent: i64,
+}
+
+const F64: Format = Format {
+    precision: 53,
+    min_exponent: -1022,
+    max_exponent: 1023,
+};
+
+const F32: Format = Format {
+    precision: 24,
+    min_exponent: -126,
+    max_exponent: 127,
+};
+
+impl Format {
+    fn bias(&self) -> i64 {
+        self.max_exponent
+    }
+
+    fn infinity(&self) -> u64 {
+        ((2 * self.max_exponent + 1) as u64) << (self.precision - 1)
+    }
+
+    fn sign(&self) -> u64 {
+        1 << (self.precision - 1 + self.exponent_bits())
+    }
+
+    fn exponent_bits(&self) -> u32 {
+        (2 * self.max_exponent + 1).ilog2() + 1
+    }
+}
+
+/// Rounds an exact value into the bits of the nearest float, ties to even.
+fn round_to_float(value: &BigRational, format: &Format) -> u64 {
+    let sign = if value.is_negative() {
+        format.sign()
+    } else {
+        0
+    };
+    let numerator = value.numerator().magnitude().clone();
+    let denominator = value.denominator().magnitude().clone();
+    if numerator.is_zero() {
+        return 0;
+    };
+
+    // Scale so that the integer quotient has two more bits than the precision, which leaves room
+    // for the rounding bit; the remainder of the division only matters as a sticky bit.
+    let shift = numerator.bits() as i64 - denominator.bits() as i64 - format.precision as i64 - 2;
+    let (numerator, denominator) = if shift < 0 {
+        (numerator << shift.unsigned_abs() as u32, denominator)
+    } else {
+        (numerator, denominator << shift as u32)
+    };
+    let (quotient, remainder) = numerator.div_rem(&denominator);
+    let bits = quotient.bits() as i64;
+
+    // The value lies in [2^(bits - 1), 2^bits) * 2^shift, keep the bits down to the least
+    // significant one of its binade, or of the subnormal range when it's below the normal range
+    let msb = bits - 1 + shift;
+    let lsb = if msb >= format.min_exponent {
+        msb - format.precision as i64 + 1
+    } else {
+        format.min_exponent - format.precision as i64 + 1
+    };
+    if lsb - shift > bits {
+        // Less than half of the smallest subnormal
+        return sign;
+    };
+    let drop = (lsb - shift) as u32;
+
+    let mut mantissa = quotient.clone() >> drop;
+    let dropped = quotient - (mantissa.clone() << drop);
+    let half = BigUint::one() << (drop - 1);
+    let round_up = match dropped.cmp(&half) {
+        Ordering::Greater => true,
+        Ordering::Equal => !remainder.is_zero() || !mantissa.is_even(),
+        Ordering::Less => false,
+    };
+    if round_up {
+        mantissa = mantissa + BigUint::one();
+    };
+    let mantissa = u64::try_from(BigInt::from_unsigned(mantissa)).expect("Mantissa fits into u64");
+
+    // Rounding up may carry into a new binade
+    let (mantissa, lsb) = if mantissa >> format.precision != 0 {
+        (mantissa >> 1, lsb + 1)
+    } else {
+        (mantissa, lsb)
+    };
+    let implicit_bit = 1 << (format.precision - 1);
+    if mantissa & implicit_bit == 0 {
+        // Subnormal, the biased exponent is zero
+        return sign | mantissa;
+    };
+    let exponent = lsb + format.precision as i64 - 1;
+    if exponent > format.max_exponent {
+        return sign | format.infinity();
+    };
+    let biased = (exponent + format.bias()) as u64;
+    sign | biased << (format.precision - 1) | (mantissa & (implicit_bit - 1))
+}
+
+/// Converts a float into the rational with exactly the same value.
+///
+/// Every finite float is a dyadic rational `m / 2^k`, so the conversion is lossless, but the
+/// denominator of a small number (or the numerator of a large one) can get very wide.
+///
+/// ```
+/// # use rational::*;
+/// assert_eq!(Ratio::<i64>::try_from(0.375), Ok(Ratio::new(3, 8)));
+/// assert_eq!(
+///     Ratio::<i64>::try_from(0.1),
+///     Ok(Ratio::new(3602879701896397, 36028797018963968))
+/// );
+/// assert!(Ratio::<i64>::try_from(f64::NAN).is_err());
+/// assert!(Ratio::<i8>::try_from(1e-3).is_err());
+/// ```
+impl<T: Integer> TryFrom<f64> for Ratio<T> {
+    type Error = TryFromFloatError;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        Ratio::from_big(to_exact(value)?).ok_or(TryFromFloatError(FloatErrorCause::OutOfRange))
+    }
+}
+
+/// Converts a float into the rational with exactly the same value.
+impl<T: Integer> TryFrom<f32> for Ratio<T> {
+    type Error = TryFromFloatError;
+
+    fn try_from(value: f32) -> Result<Self, Self::Error> {
+        // Every `f32` is exactly representable as an `f64`
+        Ratio::try_from(value as f64)
+    }
+}
+
+impl Display for TryFromFloatError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            FloatErrorCause::NotFinite => write!(f, "cannot convert a non-finite float"),
+            FloatErrorCause::OutOfRange => write!(f, "float out of range of the target type"),
+        }
+    }
+}
+
+impl Error for TryFromFloatError {}
@@ -0,0 +1,238 @@
+//! Integer types which can be used to store the numerator and the denominator of a [Rational](crate::Rational).
+
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+use std::ops::{Add, Div, Mul, Neg, Rem, Shl, Shr, Sub};
+use std::str::FromStr;
+
+use crate::BigInt;
+
+pub(crate) mod sealed {
+    pub trait Sealed {}
+}
+
+/// A signed integer type which can be used as the underlying storage of a [Rational](crate::Rational).
+///
+/// This trait is sealed and is implemented for all primitive signed integer types and [BigInt](crate::BigInt).
+pub trait Integer:
+    sealed::Sealed
+    + Clone
+    + Debug
+    + Display
+    + Eq
+    + Ord
+    + Hash
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Neg<Output = Self>
+{
+    /// The unsigned counterpart of this type, used to hold absolute values.
+    type Unsigned: Unsigned;
+
+    fn zero() -> Self;
+
+    fn one() -> Self;
+
+    /// Returns the absolute value without overflowing, even for the minimal value.
+    fn unsigned_abs(&self) -> Self::Unsigned;
+
+    /// Converts an absolute value back into this type, wrapping around when it doesn't fit.
+    fn from_unsigned(value: Self::Unsigned) -> Self;
+
+    fn checked_add(&self, rhs: &Self) -> Option<Self>;
+
+    fn checked_sub(&self, rhs: &Self) -> Option<Self>;
+
+    fn checked_mul(&self, rhs: &Self) -> Option<Self>;
+
+    fn checked_neg(&self) -> Option<Self>;
+
+    /// The smallest and the largest values of this type, None when the type is unbounded.
+    fn bounds() -> Option<(Self, Self)>;
+
+    /// Converts this value into an arbitrary-precision integer without any loss.
+    fn to_big_int(&self) -> BigInt;
+
+    /// Converts an arbitrary-precision integer into this type, None when it doesn't fit.
+    fn from_big_int(value: &BigInt) -> Option<Self>;
+}
+
+/// An unsigned integer type, used internally for absolute values of an [Integer].
+///
+/// This trait is sealed and is implemented for all primitive unsigned integer types and [BigUint](crate::BigUint).
+pub trait Unsigned:
+    sealed::Sealed
+    + Clone
+    + Debug
+    + Eq
+    + Ord
+    + Hash
+    + Display
+    + FromStr<Err: Debug>
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Shl<u32, Output = Self>
+    + Shr<u32, Output = Self>
+{
+    fn zero() -> Self;
+
+    fn one() -> Self;
+
+    fn from_u8(value: u8) -> Self;
+
+    fn is_even(&self) -> bool;
+
+    fn pow(self, exp: u32) -> Self;
+
+    fn checked_add(&self, rhs: &Self) -> Option<Self>;
+
+    fn checked_mul(&self, rhs: &Self) -> Option<Self>;
+}
+
+macro_rules! impl_integer {
+    ($($signed:ty => $unsigned:ty),* $(,)?) => {$(
+        impl sealed::Sealed for $signed {}
+        impl sealed::Sealed for $unsigned {}
+
+        impl Integer for $signed {
+            type Unsigned = $unsigned;
+
+            fn zero() -> Self {
+                0
+            }
+
+            fn one() -> Self {
+                1
+            }
+
+            fn unsigned_abs(&self) -> Self::Unsigned {
+                <$signed>::unsigned_abs(*self)
+            }
+
+            fn from_unsigned(value: Self::Unsigned) -> Self {
+                value as $signed
+            }
+
+            fn checked_add(&self, rhs: &Self) -> Option<Self> {
+                <$signed>::checked_add(*self, *rhs)
+            }
+
+            fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+                <$signed>::checked_sub(*self, *rhs)
+            }
+
+            fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+                <$signed>::checked_mul(*self, *rhs)
+            }
+
+            fn checked_neg(&self) -> Option<Self> {
+                <$signed>::checked_neg(*self)
+            }
+
+            fn bounds() -> Option<(Self, Self)> {
+                Some((<$signed>::MIN, <$signed>::MAX))
+            }
+
+            fn to_big_int(&self) -> BigInt {
+                BigInt::from(*self)
+            }
+
+            fn from_big_int(value: &BigInt) -> Option<Self> {
+                <$signed>::try_from(value.clone()).ok()
+            }
+        }
+
+        impl Unsigned for $unsigned {
+            fn zero() -> Self {
+                0
+            }
+
+            fn one() -> Self {
+                1
+            }
+
+            fn from_u8(value: u8) -> Self {
+                value as $unsigned
+            }
+
+            fn is_even(&self) -> bool {
+                self % 2 == 0
+            }
+
+            fn pow(self, exp: u32) -> Self {
+                <$unsigned>::pow(self, exp)
+            }
+
+            fn checked_add(&self, rhs: &Self) -> Option<Self> {
+                <$unsigned>::checked_add(*self, *rhs)
+            }
+
+            fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+                <$unsigned>::checked_mul(*self, *rhs)
+            }
+        }
+    )*};
+}
+
+impl_integer! {
+    i8 => u8,
+    i16 => u16,
+    i32 => u32,
+    i64 => u64,
+    i128 => u128,
+    isize => usize,
+}
+
+/// Greatest common divisor of two signed numbers, at least one of which is non-zero.
+pub(crate) fn gcd_signed<T: Integer>(a: &T, b: &T) -> T {
+    T::from_unsigned(gcd(a.unsigned_abs(), b.unsigned_abs()))
+}
+
+/// Greatest common divisor of two numbers, at least one of which is non-zero.
+pub(crate) fn gcd<U: Unsigned>(mut a: U, mut b: U) -> U {
+    if a == U::zero() {
+        return b;
+    };
+    if b == U::zero() {
+        return a;
+    };
+
+    // Simple case optimization
+    if a == U::one() || b == U::one() {
+        return U::one();
+    };
+
+    let mut shift = 0;
+
+    // Bitshift optimization technique
+    while a.is_even() && b.is_even() {
+        a = a >> 1;
+        b = b >> 1;
+        shift += 1;
+    }
+
+    while a.is_even() {
+        a = a >> 1;
+    }
+
+    while b.is_even() {
+        b = b >> 1;
+    }
+
+    // Euclid's algorithm
+    while (a != U::zero()) && (b != U::zero()) {
+        if a > b {
+            a = a % b.clone();
+        } else {
+            b = b % a.clone();
+        }
+    }
+
+    (a + b) << shift
+}
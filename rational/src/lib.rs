@@ -25,7 +25,39 @@
 //! assert_eq!(a * b, Rational::new(-1, 8));
 //! assert_eq!(a / b, Rational::new(-2, 1));
 //! ```
-//! ## Parsing from a decimal representation
+//! Operands can be borrowed, mixed with integers of the same type, and combined with assignment.
+//! ```
+//! # use rational::Rational;
+//! let mut sum = Rational::new(0, 1);
+//! for n in 1..=4 {
+//!     sum += 1 / Rational::from(n);
+//! }
+//!
+//! assert_eq!(sum, Rational::new(25, 12));
+//! assert_eq!(&sum * 12, Rational::new(25, 1));
+//! ```
+//! The operators panic when the result can't be represented.
+//! Checked, overflowing and saturating variants are available for detecting overflow.
+//! ```
+//! # use rational::Ratio;
+//! let a: Ratio<i8> = Ratio::new(100, 1);
+//!
+//! assert_eq!(a.checked_add(a), None);
+//! assert_eq!(a.overflowing_add(a), (Ratio::new(127, 1), true));
+//! assert_eq!(a.saturating_add(a), Ratio::new(127, 1));
+//! ```
+//! ## Comparing and other utilities
+//! Rationals are totally ordered, so the [Ord] methods `min`, `max` and `clamp` work as for integers.
+//! ```
+//! # use rational::Rational;
+//! let a = Rational::new(-3, 4);
+//!
+//! assert_eq!(a.clamp(Rational::new(-1, 2), Rational::new(1, 2)), Rational::new(-1, 2));
+//! assert_eq!(a.abs().max(Rational::new(2, 3)), Rational::new(3, 4));
+//! assert_eq!(a.recip(), Rational::new(-4, 3));
+//! assert!(a.is_negative() && !a.is_integer());
+//! ```
+//! ## Parsing from a decimal or fraction representation
 //! ```
 //! # use rational::Rational;
 //! let a: Rational = "1.5".parse().unwrap();
@@ -35,391 +67,287 @@
 //! let b: Rational = "0.(6)".parse().unwrap();
 //!
 //! assert_eq!(b, Rational::new(2, 3));
+//!
+//! let c: Rational = "-2 1/3".parse().unwrap();
+//!
+//! assert_eq!(c, Rational::new(-7, 3));
+//! ```
+//! Invalid strings are rejected with a [ParseRationalError] describing the problem and where it is.
+//! ```
+//! # use rational::{Rational, RationalErrorKind};
+//! let error = "1.()".parse::<Rational>().unwrap_err();
+//!
+//! assert_eq!(error.kind(), RationalErrorKind::EmptyRepeating);
+//! assert_eq!(error.to_string(), "empty repeating part at byte 2");
+//! ```
+//! ## Formatting
+//! Rationals are printed as fractions by default, the alternate flag and precision select decimal output.
+//! ```
+//! # use rational::Rational;
+//! let a = Rational::new(1, 3);
+//!
+//! assert_eq!(a.to_string(), "1/3");
+//! assert_eq!(format!("{a:#}"), "0.(3)");
+//! assert_eq!(format!("{a:.2}"), "0.33");
+//! ```
+//! ## Choosing the underlying integer type
+//! [Rational] stores the numerator and the denominator in [isize] integers.
+//! [Ratio] is generic over the signed integer type, and `Rational` is an alias for `Ratio<isize>`.
+//! ```
+//! # use rational::{Ratio, Rational};
+//! let a: Ratio<i8> = Ratio::new(3, 4);
+//! let b: Ratio<i128> = "0.(3)".parse().unwrap();
+//! let c = Rational::new(100_000, 1);
+//!
+//! assert_eq!(a.numerator(), 3);
+//! assert_eq!(b, Ratio::new(1, 3));
+//! assert_eq!(c * c, Rational::new(10_000_000_000, 1));
+//! ```
+//! ## Arbitrary precision
+//! [BigRational] stores the fraction in arbitrary-precision integers, so its arithmetic never overflows.
+//! ```
+//! # use rational::{BigRational, Ratio};
+//! let a: BigRational = "0.(3)".parse().unwrap();
+//! let b = BigRational::from(Ratio::<i64>::new(2, 3));
+//!
+//! assert_eq!(Ratio::<i64>::try_from(a + b), Ok(Ratio::new(1, 1)));
 //! ```
 //! # Performance
-//! The [Rational] struct reduces all fractions internally, which can impose a performance penalty.
+//! The [Ratio] struct reduces all fractions internally, which can impose a performance penalty.
 //!
 //! Reducing fractions is necessary for consistent results regarding integer overflow.
 //!
 //! For reducing fractions at compile-time, see [rational-proc-macro](../rational_proc_macro/index.html) crate.
 
-// TODO handle overflows
-// TODO implement Display
-
+mod approximation;
+mod big;
+mod decimal;
+mod display;
+mod float;
+mod integer;
+mod ops;
+mod overflow;
+mod parse;
+mod power;
+mod root;
+mod rounding;
 #[cfg(test)]
 mod tests;
 
-use std::borrow::Borrow;
-use std::cmp::{min, Ordering};
-use std::hash::{Hash, Hasher};
-use std::iter::Peekable;
-use std::ops::{Add, Div, Mul, Neg, Sub};
-use std::str::FromStr;
-
-type UnsignedInt = usize;
-type SignedInt = isize;
-
-#[derive(Debug, Copy, Clone, Eq)]
-pub struct Rational {
-    p: SignedInt,
-    q: SignedInt,
+pub use big::{BigInt, BigRational, BigUint, ParseBigIntError, TryFromBigIntError};
+pub use decimal::{Digit, Digits, RepeatingStyle};
+pub use display::{DisplayRatio, DisplayScaled};
+pub use float::TryFromFloatError;
+pub use integer::{Integer, Unsigned};
+pub use parse::{ParseOptions, ParseRationalError, RationalErrorKind};
+pub use rounding::{RoundingMode, TryFromRationalError};
+
+use std::cmp::Ordering;
+
+use integer::gcd_signed;
+
+/// A rational number p / q, stored as a reduced fraction of two [isize] integers.
+///
+/// See [Ratio] for the rationals over other integer types.
+pub type Rational = Ratio<isize>;
+
+/// A rational number p / q, stored as a reduced fraction of two integers of type `T`.
+///
+/// The denominator is always positive, so every rational has exactly one representation.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Ratio<T> {
+    p: T,
+    q: T,
 }
 
-impl Rational {
+impl<T: Integer> Ratio<T> {
     /// Builds a new rational from p / q and reduces the underlying fraction.
     ///
-    /// Panics when q == 0.
-    pub fn new(p: SignedInt, q: SignedInt) -> Rational {
-        if q == 0 {
+    /// Panics when q == 0, or when the reduced denominator would be `T::MIN`, which can't be made
+    /// positive, e.g. `Rational::new(1, isize::MIN)`.
+    pub fn new(p: T, q: T) -> Ratio<T> {
+        if q == T::zero() {
             panic!("Denominator can't be zero!")
         };
-        let mut res = Rational { p, q };
-        if p != 0 {
-            res.reduce()
-        };
+        let mut res = Ratio { p, q };
+        res.reduce();
         res
     }
 
     /// Creates a new Rational from p / q without reducing the fraction.
     ///
-    /// Should only be used when you are 100% certain numerator and denominator are reduced.
+    /// Should only be used when you are 100% certain numerator and denominator are reduced
+    /// and the denominator is positive. This is checked in debug builds only.
     ///
     /// Can be used for optimisations.
     ///
     /// Panics when q == 0.
-    pub fn new_unchecked(p: SignedInt, q: SignedInt) -> Rational {
-        // TODO add debug assertions
-        if q == 0 {
+    pub fn new_unchecked(p: T, q: T) -> Ratio<T> {
+        if q == T::zero() {
             panic!("Denominator can't be zero!")
         };
-        Rational { p, q }
+        debug_assert!(q > T::zero(), "Denominator must be positive");
+        debug_assert!(gcd_signed(&p, &q) == T::one(), "Fraction must be reduced");
+        Ratio { p, q }
     }
 
+    /// Reduces the fraction and makes the denominator positive.
     fn reduce(&mut self) {
-        let gcd = gcd(self.p.abs_diff(0), self.q.abs_diff(0)) as SignedInt;
-        self.p /= gcd;
-        self.q /= gcd;
+        let gcd = gcd_signed(&self.p, &self.q);
+        self.p = self.p.clone() / gcd.clone();
+        self.q = self.q.clone() / gcd;
+
+        if self.q < T::zero() {
+            let (Some(p), Some(q)) = (self.p.checked_neg(), self.q.checked_neg()) else {
+                panic!("Rational can't be represented with a positive denominator")
+            };
+            (self.p, self.q) = (p, q);
+        };
     }
 
     /// Returns the numerator of the underlying fraction.
     ///
     /// The underlying fraction is guaranteed to be reduced.
     ///
-    /// The sign of the numerator is not defined,
-    /// but it is guaranteed that a.numerator() / a.denominator() == a.
-    pub fn numerator(&self) -> SignedInt {
-        self.p
+    /// The numerator carries the sign of the rational.
+    pub fn numerator(&self) -> T {
+        self.p.clone()
     }
 
     /// Returns the denominator of the underlying fraction.
     ///
     /// The underlying fraction is guaranteed to be reduced.
     ///
-    /// The sign of the denominator is not defined,
-    /// but it is guaranteed that a.numerator() / a.denominator() == a.
-    pub fn denominator(&self) -> SignedInt {
-        self.q
+    /// The denominator is always positive.
+    pub fn denominator(&self) -> T {
+        self.q.clone()
     }
 
-    fn signum(&self) -> isize {
-        self.p.signum() * self.q.signum()
-    }
-}
-
-impl PartialEq for Rational {
-    fn eq(&self, other: &Self) -> bool {
-        self.p * other.q == self.q * other.p
-    }
-}
-
-impl Hash for Rational {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        let canonical_representation = match (self.p.signum(), self.q.signum()) {
-            (0, _) => (0, 1),
-            (_, -1) => (-self.p, -self.q),
-            _ => (self.p, self.q),
-        };
-        canonical_representation.hash(state)
-    }
-}
-
-impl From<SignedInt> for Rational {
-    fn from(value: SignedInt) -> Self {
-        Rational::new(value, 1)
-    }
-}
-
-impl FromStr for Rational {
-    type Err = &'static str;
-
-    fn from_str(value: &str) -> Result<Self, Self::Err> {
-        type DigitsSequence = str;
-        type ParsingError = &'static str;
-
-        fn parse_sign(chars_iter: &mut Peekable<impl Iterator<Item = char>>) -> SignedInt {
-            let first_char = chars_iter.peek();
-
-            match first_char {
-                Some('-') => {
-                    chars_iter.next();
-                    -1
-                }
-                _ => 1,
-            }
-        }
-
-        fn parse_integral_part(
-            chars_iter: &mut Peekable<impl Iterator<Item = char>>,
-            capacity: usize,
-        ) -> Result<impl Borrow<DigitsSequence>, ParsingError> {
-            let mut res = String::with_capacity(capacity);
-
-            while let Some(char) = chars_iter.peek() {
-                if char.is_ascii_digit() {
-                    res.push(*char);
-                    chars_iter.next();
-                } else {
-                    break;
-                }
-            }
-
-            Ok(res)
-        }
-
-        fn parse_fractional_part(
-            chars_iter: &mut Peekable<impl Iterator<Item = char>>,
-            capacity: usize,
-        ) -> Result<impl Borrow<DigitsSequence>, ParsingError> {
-            match chars_iter.peek() {
-                Some('.') => chars_iter.next(),
-                None => return Ok(String::new()),
-                _ => return Err("Error parsing string"),
-            };
-
-            let mut res = String::with_capacity(capacity);
-
-            while let Some(char) = chars_iter.peek() {
-                if char.is_ascii_digit() {
-                    res.push(*char);
-                    chars_iter.next();
-                } else {
-                    break;
-                }
-            }
-
-            Ok(res)
-        }
-
-        fn parse_repeating_part(
-            chars_iter: &mut Peekable<impl Iterator<Item = char>>,
-            capacity: usize,
-        ) -> Result<impl Borrow<DigitsSequence>, ParsingError> {
-            match chars_iter.peek() {
-                Some('(') => chars_iter.next(),
-                None => return Ok(String::new()),
-                _ => return Err("Error parsing string"),
-            };
-
-            let mut res = String::with_capacity(capacity);
-
-            while let Some(char) = chars_iter.peek() {
-                if char.is_ascii_digit() {
-                    res.push(*char);
-                    chars_iter.next();
-                } else {
-                    break;
-                }
-            }
-
-            if res.is_empty() {
-                return Err("Error parsing string");
-            };
-
-            match chars_iter.next() {
-                Some(')') => Ok(res),
-                _ => Err("Error parsing string"),
-            }
-        }
-
-        fn parse_digits_sequence(digits_sequence: &DigitsSequence) -> UnsignedInt {
-            // TODO: there are still possible parsing errors like PosOverFlow that should be handled instead of panicking
-            digits_sequence
-                .parse()
-                .expect("String must parse because it is non-empty and only contains digits, as ensured earlier")
-        }
-
-        fn get_p_q(
-            integral_part: &DigitsSequence,
-            fractional_part: &DigitsSequence,
-        ) -> (UnsignedInt, UnsignedInt) {
-            let both_parts = [integral_part, fractional_part].concat();
-
-            if both_parts.is_empty() {
-                return (0, 1);
-            }
-
-            let p = parse_digits_sequence(&both_parts);
-
-            let q = (10 as UnsignedInt).pow(fractional_part.len() as u32);
-            (p, q)
-        }
-
-        fn get_repeating_p_q(repeating_part: &DigitsSequence) -> (UnsignedInt, UnsignedInt) {
-            if repeating_part.is_empty() {
-                return (0, 1);
-            };
-
-            let p = parse_digits_sequence(repeating_part);
-
-            let mut q = 0;
-
-            for _ in 0..repeating_part.len() {
-                // TODO possible overflow
-                q *= 10;
-                q += 9;
-            }
-
-            (p, q)
-        }
-
-        let capacity = value.len();
-        let mut chars_iter = value.chars().peekable();
-
-        let sign = parse_sign(&mut chars_iter);
-        let integral_part = parse_integral_part(&mut chars_iter, capacity)?;
-        let fractional_part = parse_fractional_part(&mut chars_iter, capacity)?;
-        let repeating_part = parse_repeating_part(&mut chars_iter, capacity)?;
-
-        // make sure iterator is exhausted
-        if chars_iter.next().is_some() {
-            return Err("Error parsing string");
+    /// Returns -1, 0 or 1 depending on the sign of the rational.
+    ///
+    /// ```
+    /// # use rational::Rational;
+    /// assert_eq!(Rational::new(-3, 4).signum(), Rational::new(-1, 1));
+    /// assert_eq!(Rational::new(0, 4).signum(), Rational::new(0, 1));
+    /// ```
+    pub fn signum(&self) -> Self {
+        match self.p.cmp(&T::zero()) {
+            Ordering::Less => Ratio::new_unchecked(-T::one(), T::one()),
+            Ordering::Equal => Ratio::new_unchecked(T::zero(), T::one()),
+            Ordering::Greater => Ratio::new_unchecked(T::one(), T::one()),
         }
-
-        if integral_part.borrow().len()
-            + fractional_part.borrow().len()
-            + repeating_part.borrow().len()
-            == 0
-        {
-            return Err("Error parsing string");
-        };
-
-        let (p, q) = get_p_q(integral_part.borrow(), fractional_part.borrow());
-        let (repeating_p, repeating_q) = get_repeating_p_q(repeating_part.borrow());
-
-        Ok(Rational::new(sign * p as SignedInt, q as SignedInt)
-            + Rational::new(
-                sign * repeating_p as SignedInt,
-                (repeating_q * q) as SignedInt,
-            ))
     }
-}
-
-impl Mul for Rational {
-    type Output = Rational;
 
-    fn mul(self, rhs: Self) -> Self::Output {
-        Rational::new(self.p * rhs.p, self.q * rhs.q)
+    /// Returns the absolute value.
+    ///
+    /// Panics if the numerator is the minimal value of `T`, use [checked_abs](Ratio::checked_abs)
+    /// to detect it.
+    ///
+    /// ```
+    /// # use rational::Rational;
+    /// assert_eq!(Rational::new(-3, 4).abs(), Rational::new(3, 4));
+    /// ```
+    pub fn abs(self) -> Self {
+        self.checked_abs().expect("attempt to negate with overflow")
     }
-}
-
-impl Div for Rational {
-    type Output = Rational;
 
-    fn div(self, rhs: Self) -> Self::Output {
-        if rhs == 0.into() {
+    /// Returns the reciprocal 1 / self.
+    ///
+    /// Panics if self is zero, or if the result can't be represented. Use
+    /// [checked_recip](Ratio::checked_recip) to detect both.
+    ///
+    /// ```
+    /// # use rational::Rational;
+    /// assert_eq!(Rational::new(-3, 4).recip(), Rational::new(-4, 3));
+    /// ```
+    pub fn recip(self) -> Self {
+        if self.p == T::zero() {
             panic!("Can't divide by zero")
         };
-        Rational::new(self.p * rhs.q, self.q * rhs.p)
+        self.checked_recip()
+            .expect("attempt to divide with overflow")
     }
-}
 
-impl Add for Rational {
-    type Output = Rational;
+    /// Returns true if the rational is zero.
+    pub fn is_zero(&self) -> bool {
+        self.p == T::zero()
+    }
 
-    fn add(self, rhs: Self) -> Self::Output {
-        Rational::new((self.p * rhs.q) + (rhs.p * self.q), self.q * rhs.q)
+    /// Returns true if the rational is greater than zero.
+    pub fn is_positive(&self) -> bool {
+        self.p > T::zero()
     }
-}
 
-impl Neg for Rational {
-    type Output = Rational;
+    /// Returns true if the rational is less than zero.
+    pub fn is_negative(&self) -> bool {
+        self.p < T::zero()
+    }
 
-    fn neg(self) -> Self::Output {
-        let Rational { p, q } = self;
-        Rational { p: -p, q }
+    /// Returns true if the rational is a whole number.
+    ///
+    /// ```
+    /// # use rational::Rational;
+    /// assert!(Rational::new(6, 3).is_integer());
+    /// assert!(!Rational::new(6, 4).is_integer());
+    /// ```
+    pub fn is_integer(&self) -> bool {
+        self.q == T::one()
     }
 }
 
-impl Sub for Rational {
-    type Output = Rational;
-
-    fn sub(self, rhs: Self) -> Self::Output {
-        self + (-rhs)
+impl<T: Integer> From<T> for Ratio<T> {
+    fn from(value: T) -> Self {
+        Ratio::new(value, T::one())
     }
 }
 
-impl PartialOrd for Rational {
+impl<T: Integer> PartialOrd for Ratio<T> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
     }
 }
 
-impl Ord for Rational {
+impl<T: Integer> Ord for Ratio<T> {
     fn cmp(&self, other: &Self) -> Ordering {
-        fn compare_abs(one: &Rational, other: &Rational) -> Ordering {
-            (one.p * other.q).abs().cmp(&(other.p * one.q).abs())
+        fn compare_abs<T: Integer>(one: &Ratio<T>, other: &Ratio<T>) -> Ordering {
+            compare_fractions(
+                one.p.unsigned_abs(),
+                one.q.unsigned_abs(),
+                other.p.unsigned_abs(),
+                other.q.unsigned_abs(),
+            )
         }
 
         use Ordering::*;
-        match (self.signum(), other.signum()) {
-            (-1, -1) => compare_abs(self, other).reverse(),
-            (-1, 0) => Less,
-            (-1, 1) => Less,
-
-            (0, -1) => Greater,
-            (0, 0) => Equal,
-            (0, 1) => Less,
-
-            (1, -1) => Greater,
-            (1, 0) => Greater,
-            (1, 1) => compare_abs(self, other),
-
-            _ => unreachable!(".signum() can only return values -1, 0 or 1"),
+        match (self.p.cmp(&T::zero()), other.p.cmp(&T::zero())) {
+            (Less, Less) => compare_abs(self, other).reverse(),
+            (Greater, Greater) => compare_abs(self, other),
+            // Different signs, or both zero
+            (sign, other_sign) => sign.cmp(&other_sign),
         }
     }
 }
 
-fn gcd(mut a: UnsignedInt, mut b: UnsignedInt) -> UnsignedInt {
-    // Simple case optimization
-    if min(a, b) == 1 {
-        return 1;
-    };
-
-    let mut d = 1;
-
-    // Bitshift optimization technique
-    while (a % 2 == 0) && (b % 2 == 0) {
-        a /= 2;
-        b /= 2;
-        d *= 2;
-    }
-
-    while a % 2 == 0 {
-        a /= 2;
-    }
+/// Compares a / b with c / d without multiplying, so it can't overflow.
+///
+/// Compares the integral parts first, and then the reciprocals of the fractional parts,
+/// which is equivalent to comparing the continued fraction expansions.
+fn compare_fractions<U: Unsigned>(mut a: U, mut b: U, mut c: U, mut d: U) -> Ordering {
+    loop {
+        let integral_ordering = (a.clone() / b.clone()).cmp(&(c.clone() / d.clone()));
+        if integral_ordering != Ordering::Equal {
+            return integral_ordering;
+        };
 
-    while b % 2 == 0 {
-        b /= 2;
-    }
+        let (fractional_a, fractional_c) = (a % b.clone(), c % d.clone());
+        match (fractional_a == U::zero(), fractional_c == U::zero()) {
+            (true, true) => return Ordering::Equal,
+            (true, false) => return Ordering::Less,
+            (false, true) => return Ordering::Greater,
+            (false, false) => {}
+        };
 
-    // Euclid's algorithm
-    while (a != 0) && (b != 0) {
-        if a > b {
-            a %= b;
-        } else {
-            b %= a;
-        }
+        // x / b < y / d <=> d / y < b / x
+        (a, b, c, d) = (d, fractional_c, b, fractional_a);
     }
-
-    (a + b) * d
 }
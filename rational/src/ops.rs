@@ -0,0 +1,208 @@
+//! Arithmetic operators for every combination of owned and borrowed operands.
+//!
+//! Operators panic on overflow, the `checked_*` methods can be used to detect it instead.
+//!
+//! Integer operands, owned or borrowed and on either side, have to be of the same type `T` as the
+//! rational. Accepting other widths too would make untyped literals like the `2` in `a * 2`
+//! ambiguous, so they have to be converted first, e.g. with `i64::from`.
+
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+use crate::{BigInt, Integer, Ratio};
+
+fn add<T: Integer>(lhs: &Ratio<T>, rhs: &Ratio<T>) -> Ratio<T> {
+    lhs.checked_add_ref(rhs)
+        .expect("attempt to add with overflow")
+}
+
+fn sub<T: Integer>(lhs: &Ratio<T>, rhs: &Ratio<T>) -> Ratio<T> {
+    lhs.checked_sub_ref(rhs)
+        .expect("attempt to subtract with overflow")
+}
+
+fn mul<T: Integer>(lhs: &Ratio<T>, rhs: &Ratio<T>) -> Ratio<T> {
+    lhs.checked_mul_ref(rhs)
+        .expect("attempt to multiply with overflow")
+}
+
+fn div<T: Integer>(lhs: &Ratio<T>, rhs: &Ratio<T>) -> Ratio<T> {
+    if rhs.p == T::zero() {
+        panic!("Can't divide by zero")
+    };
+    lhs.checked_div_ref(rhs)
+        .expect("attempt to divide with overflow")
+}
+
+/// Has the sign of `lhs`, like the remainder of primitive integers.
+fn rem<T: Integer>(lhs: &Ratio<T>, rhs: &Ratio<T>) -> Ratio<T> {
+    if rhs.p == T::zero() {
+        panic!("Can't divide by zero")
+    };
+    lhs.checked_rem_ref(rhs)
+        .expect("attempt to calculate the remainder with overflow")
+}
+
+macro_rules! impl_binary_op {
+    ($op:ident, $method:ident, $assign_op:ident, $assign_method:ident, $function:ident) => {
+        impl<T: Integer> $op for Ratio<T> {
+            type Output = Ratio<T>;
+
+            fn $method(self, rhs: Self) -> Self::Output {
+                $function(&self, &rhs)
+            }
+        }
+
+        impl<T: Integer> $op<&Ratio<T>> for Ratio<T> {
+            type Output = Ratio<T>;
+
+            fn $method(self, rhs: &Ratio<T>) -> Self::Output {
+                $function(&self, rhs)
+            }
+        }
+
+        impl<T: Integer> $op<Ratio<T>> for &Ratio<T> {
+            type Output = Ratio<T>;
+
+            fn $method(self, rhs: Ratio<T>) -> Self::Output {
+                $function(self, &rhs)
+            }
+        }
+
+        impl<T: Integer> $op<&Ratio<T>> for &Ratio<T> {
+            type Output = Ratio<T>;
+
+            fn $method(self, rhs: &Ratio<T>) -> Self::Output {
+                $function(self, rhs)
+            }
+        }
+
+        impl<T: Integer> $op<T> for Ratio<T> {
+            type Output = Ratio<T>;
+
+            fn $method(self, rhs: T) -> Self::Output {
+                $function(&self, &Ratio::from(rhs))
+            }
+        }
+
+        impl<T: Integer> $op<T> for &Ratio<T> {
+            type Output = Ratio<T>;
+
+            fn $method(self, rhs: T) -> Self::Output {
+                $function(self, &Ratio::from(rhs))
+            }
+        }
+
+        impl<T: Integer> $op<&T> for Ratio<T> {
+            type Output = Ratio<T>;
+
+            fn $method(self, rhs: &T) -> Self::Output {
+                $function(&self, &Ratio::from(rhs.clone()))
+            }
+        }
+
+        impl<T: Integer> $op<&T> for &Ratio<T> {
+            type Output = Ratio<T>;
+
+            fn $method(self, rhs: &T) -> Self::Output {
+                $function(self, &Ratio::from(rhs.clone()))
+            }
+        }
+
+        impl<T: Integer> $assign_op for Ratio<T> {
+            fn $assign_method(&mut self, rhs: Self) {
+                *self = $function(self, &rhs);
+            }
+        }
+
+        impl<T: Integer> $assign_op<&Ratio<T>> for Ratio<T> {
+            fn $assign_method(&mut self, rhs: &Ratio<T>) {
+                *self = $function(self, rhs);
+            }
+        }
+
+        impl<T: Integer> $assign_op<T> for Ratio<T> {
+            fn $assign_method(&mut self, rhs: T) {
+                *self = $function(self, &Ratio::from(rhs));
+            }
+        }
+
+        impl<T: Integer> $assign_op<&T> for Ratio<T> {
+            fn $assign_method(&mut self, rhs: &T) {
+                *self = $function(self, &Ratio::from(rhs.clone()));
+            }
+        }
+    };
+}
+
+impl_binary_op!(Add, add, AddAssign, add_assign, add);
+impl_binary_op!(Sub, sub, SubAssign, sub_assign, sub);
+impl_binary_op!(Mul, mul, MulAssign, mul_assign, mul);
+impl_binary_op!(Div, div, DivAssign, div_assign, div);
+impl_binary_op!(Rem, rem, RemAssign, rem_assign, rem);
+
+/// Implements operators with an owned or borrowed integer on the left and a rational of the same
+/// integer type on the right.
+macro_rules! impl_integer_lhs {
+    ($($int:ty),*) => {$(
+        impl_integer_lhs!(@op $int, Add, add);
+        impl_integer_lhs!(@op $int, Sub, sub);
+        impl_integer_lhs!(@op $int, Mul, mul);
+        impl_integer_lhs!(@op $int, Div, div);
+        impl_integer_lhs!(@op $int, Rem, rem);
+    )*};
+    (@op $int:ty, $op:ident, $method:ident) => {
+        impl $op<Ratio<$int>> for $int {
+            type Output = Ratio<$int>;
+
+            fn $method(self, rhs: Ratio<$int>) -> Self::Output {
+                $method(&Ratio::from(self), &rhs)
+            }
+        }
+
+        impl $op<&Ratio<$int>> for $int {
+            type Output = Ratio<$int>;
+
+            fn $method(self, rhs: &Ratio<$int>) -> Self::Output {
+                $method(&Ratio::from(self), rhs)
+            }
+        }
+
+        impl $op<Ratio<$int>> for &$int {
+            type Output = Ratio<$int>;
+
+            fn $method(self, rhs: Ratio<$int>) -> Self::Output {
+                $method(&Ratio::from(self.clone()), &rhs)
+            }
+        }
+
+        impl $op<&Ratio<$int>> for &$int {
+            type Output = Ratio<$int>;
+
+            fn $method(self, rhs: &Ratio<$int>) -> Self::Output {
+                $method(&Ratio::from(self.clone()), rhs)
+            }
+        }
+    };
+}
+
+impl_integer_lhs!(i8, i16, i32, i64, i128, isize, BigInt);
+
+impl<T: Integer> Neg for Ratio<T> {
+    type Output = Ratio<T>;
+
+    fn neg(self) -> Self::Output {
+        self.checked_neg().expect("attempt to negate with overflow")
+    }
+}
+
+impl<T: Integer> Neg for &Ratio<T> {
+    type Output = Ratio<T>;
+
+    fn neg(self) -> Self::Output {
+        self.clone()
+            .checked_neg()
+            .expect("attempt to negate with overflow")
+    }
+}
@@ -0,0 +1,266 @@
+//! Arithmetic which detects integer overflow instead of panicking.
+//!
+//! There is no `wrapping_*` family: wrapping the numerator and the denominator separately doesn't give
+//! a meaningful rational, and the denominator could even wrap around to zero. For the same reason the
+//! `overflowing_*` methods return the saturated result along with the flag, where the primitive
+//! integers return the wrapped one.
+
+use std::cmp::Ordering;
+
+use crate::approximation::best_approximation;
+use crate::integer::gcd_signed;
+use crate::{BigRational, Integer, Ratio};
+
+impl<T: Integer> Ratio<T> {
+    /// Checked addition. Returns None if the result can't be represented.
+    ///
+    /// ```
+    /// # use rational::Ratio;
+    /// let a: Ratio<i8> = Ratio::new(1, 2);
+    /// assert_eq!(a.checked_add(a), Some(Ratio::new(1, 1)));
+    /// assert_eq!(a.checked_add(Ratio::new(1, 127)), None);
+    /// ```
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.checked_add_ref(&rhs)
+    }
+
+    /// Checked subtraction. Returns None if the result can't be represented.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.checked_sub_ref(&rhs)
+    }
+
+    /// Checked multiplication. Returns None if the result can't be represented.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        self.checked_mul_ref(&rhs)
+    }
+
+    /// Checked division. Returns None if rhs == 0 or the result can't be represented.
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.checked_div_ref(&rhs)
+    }
+
+    /// Checked negation. Returns None if the result can't be represented.
+    pub fn checked_neg(self) -> Option<Self> {
+        let p = self.p.checked_neg()?;
+        Some(Ratio { p, q: self.q })
+    }
+
+    /// Checked absolute value. Returns None if the result can't be represented.
+    pub fn checked_abs(self) -> Option<Self> {
+        if self.p < T::zero() {
+            self.checked_neg()
+        } else {
+            Some(self)
+        }
+    }
+
+    /// Checked reciprocal. Returns None if self == 0 or the result can't be represented.
+    ///
+    /// ```
+    /// # use rational::Ratio;
+    /// let a: Ratio<i8> = Ratio::new(-2, 5);
+    /// assert_eq!(a.checked_recip(), Some(Ratio::new(-5, 2)));
+    /// assert_eq!(Ratio::<i8>::new(0, 1).checked_recip(), None);
+    /// assert_eq!(Ratio::<i8>::new(-128, 1).checked_recip(), None);
+    /// ```
+    pub fn checked_recip(self) -> Option<Self> {
+        match self.p.cmp(&T::zero()) {
+            Ordering::Less => Some(Ratio {
+                p: self.q.checked_neg()?,
+                q: self.p.checked_neg()?,
+            }),
+            Ordering::Equal => None,
+            Ordering::Greater => Some(Ratio {
+                p: self.q,
+                q: self.p,
+            }),
+        }
+    }
+
+    /// Calculates self + rhs.
+    ///
+    /// Returns a tuple of the result and a boolean indicating whether an overflow happened. On
+    /// overflow the result is clamped like in [saturating_add](Ratio::saturating_add), not wrapped.
+    pub fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        match self.checked_add_ref(&rhs) {
+            Some(res) => (res, false),
+            None => (saturate(self.to_big() + rhs.to_big()), true),
+        }
+    }
+
+    /// Calculates self - rhs.
+    ///
+    /// Returns a tuple of the result and a boolean indicating whether an overflow happened. On
+    /// overflow the result is clamped like in [saturating_add](Ratio::saturating_add), not wrapped.
+    pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        match self.checked_sub_ref(&rhs) {
+            Some(res) => (res, false),
+            None => (saturate(self.to_big() - rhs.to_big()), true),
+        }
+    }
+
+    /// Calculates self * rhs.
+    ///
+    /// Returns a tuple of the result and a boolean indicating whether an overflow happened. On
+    /// overflow the result is clamped like in [saturating_add](Ratio::saturating_add), not wrapped.
+    pub fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+        match self.checked_mul_ref(&rhs) {
+            Some(res) => (res, false),
+            None => (saturate(self.to_big() * rhs.to_big()), true),
+        }
+    }
+
+    /// Calculates self / rhs.
+    ///
+    /// Returns a tuple of the result and a boolean indicating whether an overflow happened. On
+    /// overflow the result is clamped like in [saturating_add](Ratio::saturating_add), not wrapped.
+    ///
+    /// Panics when rhs == 0.
+    pub fn overflowing_div(self, rhs: Self) -> (Self, bool) {
+        if rhs.p == T::zero() {
+            panic!("Can't divide by zero")
+        };
+        match self.checked_div_ref(&rhs) {
+            Some(res) => (res, false),
+            None => (saturate(self.to_big() / rhs.to_big()), true),
+        }
+    }
+
+    /// Saturating addition.
+    ///
+    /// When the result is out of the range of `T`, returns the nearest bound of that range.
+    /// When the result is within the range but can't be represented exactly,
+    /// returns the closest representable rational.
+    ///
+    /// ```
+    /// # use rational::Ratio;
+    /// let a: Ratio<i8> = Ratio::from(100);
+    /// assert_eq!(a.saturating_add(a), Ratio::from(127));
+    ///
+    /// let b: Ratio<i8> = Ratio::new(1, 127);
+    /// assert_eq!(b.saturating_add(Ratio::new(1, 125)), Ratio::new(1, 63));
+    /// ```
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        self.overflowing_add(rhs).0
+    }
+
+    /// Saturating subtraction, see [Ratio::saturating_add] for the details.
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        self.overflowing_sub(rhs).0
+    }
+
+    /// Saturating multiplication, see [Ratio::saturating_add] for the details.
+    pub fn saturating_mul(self, rhs: Self) -> Self {
+        self.overflowing_mul(rhs).0
+    }
+
+    /// Saturating division, see [Ratio::saturating_add] for the details.
+    ///
+    /// Panics when rhs == 0.
+    pub fn saturating_div(self, rhs: Self) -> Self {
+        self.overflowing_div(rhs).0
+    }
+
+    // The algorithms below reduce the operands by common divisors before multiplying
+    // (The Art of Computer Programming, vol. 2, 4.5.1), so the result is already reduced.
+    // When an intermediate value still overflows, the result is calculated exactly and converted back,
+    // so None is only returned when the reduced result doesn't fit.
+
+    pub(crate) fn checked_add_ref(&self, rhs: &Self) -> Option<Self> {
+        self.add_reduced(rhs, T::checked_add)
+            .or_else(|| Ratio::from_big(self.to_big() + rhs.to_big()))
+    }
+
+    pub(crate) fn checked_sub_ref(&self, rhs: &Self) -> Option<Self> {
+        self.add_reduced(rhs, T::checked_sub)
+            .or_else(|| Ratio::from_big(self.to_big() - rhs.to_big()))
+    }
+
+    pub(crate) fn checked_mul_ref(&self, rhs: &Self) -> Option<Self> {
+        self.mul_reduced(&rhs.p, &rhs.q)
+            .or_else(|| Ratio::from_big(self.to_big() * rhs.to_big()))
+    }
+
+    pub(crate) fn checked_div_ref(&self, rhs: &Self) -> Option<Self> {
+        if rhs.p == T::zero() {
+            return None;
+        };
+        self.div_reduced(rhs)
+            .or_else(|| Ratio::from_big(self.to_big() / rhs.to_big()))
+    }
+
+    /// Calculates self + rhs or self - rhs, depending on `combine`.
+    fn add_reduced(&self, rhs: &Self, combine: impl Fn(&T, &T) -> Option<T>) -> Option<Self> {
+        let d1 = gcd_signed(&self.q, &rhs.q);
+
+        if d1 == T::one() {
+            let p = combine(&self.p.checked_mul(&rhs.q)?, &rhs.p.checked_mul(&self.q)?)?;
+            let q = self.q.checked_mul(&rhs.q)?;
+            return Some(Ratio { p, q });
+        };
+
+        let t = combine(
+            &self.p.checked_mul(&(rhs.q.clone() / d1.clone()))?,
+            &rhs.p.checked_mul(&(self.q.clone() / d1.clone()))?,
+        )?;
+        let d2 = gcd_signed(&t, &d1);
+
+        let p = t / d2.clone();
+        let q = (self.q.clone() / d1).checked_mul(&(rhs.q.clone() / d2))?;
+        Some(Ratio { p, q })
+    }
+
+    /// Calculates self * (p / q).
+    fn mul_reduced(&self, p: &T, q: &T) -> Option<Self> {
+        let g1 = gcd_signed(&self.p, q);
+        let g2 = gcd_signed(p, &self.q);
+
+        let res_p = (self.p.clone() / g1.clone()).checked_mul(&(p.clone() / g2.clone()))?;
+        let res_q = (self.q.clone() / g2).checked_mul(&(q.clone() / g1))?;
+        Some(Ratio { p: res_p, q: res_q })
+    }
+
+    /// Calculates self / rhs, keeping the denominator positive.
+    fn div_reduced(&self, rhs: &Self) -> Option<Self> {
+        if rhs.p < T::zero() {
+            self.mul_reduced(&rhs.q.checked_neg()?, &rhs.p.checked_neg()?)
+        } else {
+            self.mul_reduced(&rhs.q, &rhs.p)
+        }
+    }
+
+    pub(crate) fn from_big(value: BigRational) -> Option<Self> {
+        Some(Ratio {
+            p: T::from_big_int(&value.p)?,
+            q: T::from_big_int(&value.q)?,
+        })
+    }
+
+    pub(crate) fn to_big(&self) -> BigRational {
+        Ratio::new_unchecked(self.p.to_big_int(), self.q.to_big_int())
+    }
+}
+
+/// Converts an exact result into the closest rational representable with `T`.
+fn saturate<T: Integer>(exact: BigRational) -> Ratio<T> {
+    let (min, max) = T::bounds().expect("Unbounded integers never overflow");
+
+    if exact < Ratio::from(min.to_big_int()) {
+        return Ratio::from(min);
+    };
+    if exact > Ratio::from(max.to_big_int()) {
+        return Ratio::from(max);
+    };
+
+    // Negative numerators reach down to MIN, whose magnitude is larger than MAX
+    let max_numerator = if exact.is_negative() {
+        -min.to_big_int()
+    } else {
+        max.to_big_int()
+    };
+    let approximation = best_approximation(&exact, Some(&max_numerator), &max.to_big_int());
+    Ratio::new_unchecked(
+        T::from_big_int(&approximation.numerator()).expect("Numerator is within bounds"),
+        T::from_big_int(&approximation.denominator()).expect("Denominator is within bounds"),
+    )
+}
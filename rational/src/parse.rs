@@ -0,0 +1,850 @@
+//! Parsing rationals from strings.
+
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use crate::integer::gcd;
+use crate::{BigInt, BigRational, BigUint, Integer, Ratio, Unsigned};
+
+/// The error returned when a string can't be parsed into a [Ratio].
+///
+/// Carries the reason of the failure and the byte offset in the string where it was found.
+/// ```
+/// # use rational::{Rational, RationalErrorKind};
+/// let error = "1.2(3".parse::<Rational>().unwrap_err();
+///
+/// assert_eq!(error.kind(), RationalErrorKind::UnclosedRepeating);
+/// assert_eq!(error.position(), 3);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseRationalError {
+    kind: RationalErrorKind,
+    position: usize,
+}
+
+/// The reason parsing a [Ratio] failed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RationalErrorKind {
+    /// The string, or a part of it that requires digits, contains none, e.g. `""`, `"-."` or `"1/"`.
+    Empty,
+    /// A character isn't allowed at its position, e.g. the second point in `"1.2.3"`.
+    InvalidChar,
+    /// The repeating part is opened but never closed, e.g. `"0.(3"`.
+    UnclosedRepeating,
+    /// The repeating part contains no digits, e.g. `"1.()"`.
+    EmptyRepeating,
+    /// The value doesn't fit into the integer type of the rational.
+    Overflow,
+    /// The denominator of a fraction is zero, e.g. `"1/0"`.
+    ZeroDenominator,
+    /// A symbol the notation requires is missing, e.g. the `%` of a percentage.
+    MissingSymbol,
+    /// The period of a decimal written with an ellipsis can't be inferred, e.g. `"0.12..."`.
+    UnknownPeriod,
+}
+
+impl ParseRationalError {
+    fn new(kind: RationalErrorKind, position: usize) -> ParseRationalError {
+        ParseRationalError { kind, position }
+    }
+
+    /// Returns the reason parsing failed.
+    pub fn kind(&self) -> RationalErrorKind {
+        self.kind
+    }
+
+    /// Returns the byte offset in the string where the problem was found.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl Display for ParseRationalError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let description = match self.kind {
+            RationalErrorKind::Empty => "no digits in rational literal",
+            RationalErrorKind::InvalidChar => "invalid character in rational literal",
+            RationalErrorKind::UnclosedRepeating => "unclosed repeating part",
+            RationalErrorKind::EmptyRepeating => "empty repeating part",
+            RationalErrorKind::Overflow => "rational out of range of the integer type",
+            RationalErrorKind::ZeroDenominator => "zero denominator",
+            RationalErrorKind::MissingSymbol => "missing symbol in rational literal",
+            RationalErrorKind::UnknownPeriod => "period of the repeating part can't be inferred",
+        };
+        write!(f, "{description} at byte {}", self.position)
+    }
+}
+
+impl Error for ParseRationalError {}
+
+/// Parses a rational from one of the notations:
+///
+/// * a decimal, e.g. `-3.5(142857)`, where the repeating part in parentheses is optional
+///   and requires a decimal point before it,
+/// * a decimal with the repeating part in brackets `0.1[6]`, or overlined by a combining overline (U+0305)
+///   after each digit `0.16̅`,
+/// * a decimal with the repeating part inferred from the digits before an ellipsis (`...` or `…`).
+///   The period is the one repeating over the longest tail of the digits, and it has to repeat at least twice,
+///   so `0.1666...` is `0.1(6)` and `0.142857142857...` is `0.(142857)`, while `0.3...` is rejected,
+/// * a decimal in scientific notation, e.g. `1.25e-7` or `3.(3)E2`,
+/// * a fraction `p/q`, e.g. `3/4` or `3/-4`, where both the numerator and the denominator may be negative,
+/// * a mixed number, e.g. `-2 1/3`, i.e. an integer and a fraction separated by whitespace.
+impl<T: Integer> FromStr for Ratio<T> {
+    type Err = ParseRationalError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ratio::parse(value, 10, ParseOptions::new())
+    }
+}
+
+/// Options of [Ratio::parse_with], each allowing a more lenient input than [FromStr] accepts.
+///
+/// All of them are disabled by default.
+/// ```
+/// # use rational::{ParseOptions, Rational};
+/// let options = ParseOptions::new().plus_sign(true).underscores(true).whitespace(true);
+///
+/// assert_eq!(Rational::parse_with(" +1_000.25 ", &options), Ok(Rational::new(4001, 4)));
+/// assert!(" +1_000.25 ".parse::<Rational>().is_err());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub struct ParseOptions {
+    plus_sign: bool,
+    underscores: bool,
+    commas: bool,
+    whitespace: bool,
+    unicode_minus: bool,
+}
+
+impl ParseOptions {
+    /// Creates options with all leniencies disabled, accepting the same strings as [FromStr].
+    pub fn new() -> ParseOptions {
+        ParseOptions::default()
+    }
+
+    /// Creates options with all leniencies enabled.
+    pub fn lenient() -> ParseOptions {
+        ParseOptions {
+            plus_sign: true,
+            underscores: true,
+            commas: true,
+            whitespace: true,
+            unicode_minus: true,
+        }
+    }
+
+    /// Allows a `+` wherever a `-` is allowed, e.g. `+1.5` or `1/+2`.
+    pub fn plus_sign(mut self, allow: bool) -> ParseOptions {
+        self.plus_sign = allow;
+        self
+    }
+
+    /// Allows `_` between two digits, e.g. `1_000.000_1`.
+    pub fn underscores(mut self, allow: bool) -> ParseOptions {
+        self.underscores = allow;
+        self
+    }
+
+    /// Allows `,` between two digits as a grouping separator, e.g. `1,234.5`.
+    pub fn commas(mut self, allow: bool) -> ParseOptions {
+        self.commas = allow;
+        self
+    }
+
+    /// Allows whitespace before and after the number.
+    pub fn whitespace(mut self, allow: bool) -> ParseOptions {
+        self.whitespace = allow;
+        self
+    }
+
+    /// Allows the minus sign `−` (U+2212) wherever a `-` is allowed.
+    pub fn unicode_minus(mut self, allow: bool) -> ParseOptions {
+        self.unicode_minus = allow;
+        self
+    }
+
+    fn is_separator(&self, char: char) -> bool {
+        char == '_' && self.underscores || char == ',' && self.commas
+    }
+}
+
+impl<T: Integer> Ratio<T> {
+    /// Parses a rational written in the given radix, in the same notations as [FromStr] accepts,
+    /// except the scientific notation, which is only available in radix 10.
+    ///
+    /// Digits above 9 are letters, either lowercase or uppercase.
+    ///
+    /// Panics when the radix is not in the range from 2 to 36.
+    /// ```
+    /// # use rational::Rational;
+    /// assert_eq!(Rational::from_str_radix("0.(01)", 2), Ok(Rational::new(1, 3)));
+    /// assert_eq!(Rational::from_str_radix("-ff/1A", 16), Ok(Rational::new(-255, 26)));
+    /// ```
+    pub fn from_str_radix(value: &str, radix: u32) -> Result<Self, ParseRationalError> {
+        assert!(
+            (2..=36).contains(&radix),
+            "Radix must be in the range from 2 to 36"
+        );
+        Ratio::parse(value, radix, ParseOptions::new())
+    }
+
+    /// Parses a rational in the notations [FromStr] accepts, with the leniencies enabled in the options.
+    /// ```
+    /// # use rational::{ParseOptions, Rational};
+    /// let options = ParseOptions::lenient();
+    ///
+    /// assert_eq!(Rational::parse_with("\u{2212}1,234.5", &options), Ok(Rational::new(-2469, 2)));
+    /// assert_eq!(Rational::parse_with("+3/+4\n", &options), Ok(Rational::new(3, 4)));
+    /// ```
+    pub fn parse_with(value: &str, options: &ParseOptions) -> Result<Self, ParseRationalError> {
+        Ratio::parse(value, 10, *options)
+    }
+
+    /// Parses a percentage, i.e. a number in any notation [FromStr] accepts followed by `%`.
+    /// ```
+    /// # use rational::Rational;
+    /// assert_eq!(Rational::parse_percent("12.5%"), Ok(Rational::new(1, 8)));
+    /// assert_eq!(Rational::parse_percent("-1/3%"), Ok(Rational::new(-1, 300)));
+    /// ```
+    pub fn parse_percent(value: &str) -> Result<Self, ParseRationalError> {
+        Ratio::parse_scaled(value, '%', 100)
+    }
+
+    /// Parses a per-mille value, i.e. a number in any notation [FromStr] accepts followed by `‰`.
+    /// ```
+    /// # use rational::Rational;
+    /// assert_eq!(Rational::parse_per_mille("7‰"), Ok(Rational::new(7, 1000)));
+    /// ```
+    pub fn parse_per_mille(value: &str) -> Result<Self, ParseRationalError> {
+        Ratio::parse_scaled(value, '‰', 1000)
+    }
+
+    /// Parses a ratio `a:b` of two numbers in any notation [FromStr] accepts.
+    ///
+    /// Only the value of the ratio has to fit into `T`, not the numbers themselves.
+    /// ```
+    /// # use rational::Rational;
+    /// assert_eq!(Rational::parse_ratio("16:9"), Ok(Rational::new(16, 9)));
+    /// assert_eq!(Rational::parse_ratio("1.5:-0.25"), Ok(Rational::new(-6, 1)));
+    /// ```
+    pub fn parse_ratio(value: &str) -> Result<Self, ParseRationalError> {
+        let mut parser = Parser::new(value, 10, ParseOptions::new());
+        let antecedent = parser.parse_literal()?;
+        parser.expect_symbol(':')?;
+        let consequent_position = parser.position;
+        let consequent = parser.parse_literal()?;
+        parser.expect_end()?;
+
+        let consequent = consequent.to_big(10, None)?;
+        if consequent.numerator().is_zero() {
+            return Err(ParseRationalError::new(
+                RationalErrorKind::ZeroDenominator,
+                consequent_position,
+            ));
+        };
+        let value = antecedent.to_big(10, None)? / consequent;
+        Ratio::from_big(value).ok_or(antecedent.overflow())
+    }
+
+    /// Parses a number followed by the symbol, dividing it by the scale.
+    fn parse_scaled(value: &str, symbol: char, scale: u32) -> Result<Self, ParseRationalError> {
+        let mut parser = Parser::new(value, 10, ParseOptions::new());
+        let literal = parser.parse_literal()?;
+        parser.expect_symbol(symbol)?;
+        parser.expect_end()?;
+
+        let scale_digits = scale.to_string().len();
+        let value = literal.to_big(10, max_digits::<T>().map(|digits| digits + scale_digits))?
+            / Ratio::from(BigInt::from(scale));
+        Ratio::from_big(value).ok_or(literal.overflow())
+    }
+
+    fn parse(value: &str, radix: u32, options: ParseOptions) -> Result<Self, ParseRationalError> {
+        let mut parser = Parser::new(value, radix, options);
+        if options.whitespace {
+            parser.skip_whitespace();
+        };
+        let literal = parser.parse_literal()?;
+        if options.whitespace {
+            parser.skip_whitespace();
+        };
+        parser.expect_end()?;
+        literal.to_rational(radix)
+    }
+}
+
+/// A parsed number, before its value is calculated.
+enum Literal<'a> {
+    Decimal(Decimal<'a>),
+    Fraction(Fraction<'a>),
+}
+
+impl Literal<'_> {
+    fn to_rational<T: Integer>(&self, radix: u32) -> Result<Ratio<T>, ParseRationalError> {
+        // Big numbers are only needed when an intermediate value overflows, or for unbounded types
+        // where the exponent has to be limited first
+        if T::bounds().is_some() {
+            let value = match self {
+                Literal::Decimal(decimal) => decimal.to_fixed_width(radix),
+                Literal::Fraction(fraction) => fraction.to_fixed_width(radix),
+            };
+            if let Some(value) = value {
+                return Ok(value);
+            };
+        };
+        // The value is calculated exactly, so nothing overflows `T` on the way
+        let value = self.to_big(radix, max_digits::<T>())?;
+        Ratio::from_big(value).ok_or(self.overflow())
+    }
+
+    /// Calculates the exact value. Values known to be larger than 10^max_digits may be reported as overflow instead.
+    fn to_big(
+        &self,
+        radix: u32,
+        max_digits: Option<usize>,
+    ) -> Result<BigRational, ParseRationalError> {
+        match self {
+            Literal::Decimal(decimal) => decimal.to_big(radix, max_digits),
+            Literal::Fraction(fraction) => fraction.to_big(radix),
+        }
+    }
+
+    fn overflow(&self) -> ParseRationalError {
+        let position = match self {
+            Literal::Decimal(decimal) => decimal.position,
+            Literal::Fraction(fraction) => fraction.position,
+        };
+        ParseRationalError::new(RationalErrorKind::Overflow, position)
+    }
+}
+
+/// Number of decimal digits of the largest value of `T`, or None when `T` is unbounded.
+fn max_digits<T: Integer>() -> Option<usize> {
+    T::bounds().map(|(_, max)| max.to_string().len())
+}
+
+/// The parts of a positional (e.g. decimal) representation, as sequences of digits.
+struct Decimal<'a> {
+    /// Byte offset of the start of the number.
+    position: usize,
+    negative: bool,
+    integral: &'a str,
+    fractional: &'a str,
+    repeating: &'a str,
+    exponent_negative: bool,
+    /// Digits of the exponent, empty when there is none.
+    exponent: &'a str,
+}
+
+impl Decimal<'_> {
+    /// Calculates the value with the unsigned counterpart of `T`, None when anything overflows.
+    fn to_fixed_width<T: Integer>(&self, radix: u32) -> Option<Ratio<T>> {
+        let p = accumulate_digits(T::Unsigned::zero(), self.integral, radix)?;
+        let mut p = accumulate_digits(p, self.fractional, radix)?;
+        let mut q = checked_power::<T::Unsigned>(radix, digit_count(self.fractional))?;
+
+        if !self.repeating.is_empty() {
+            let period = checked_power::<T::Unsigned>(radix, digit_count(self.repeating))?
+                - T::Unsigned::one();
+            let repeating_p = accumulate_digits(T::Unsigned::zero(), self.repeating, radix)?;
+            p = p.checked_mul(&period)?.checked_add(&repeating_p)?;
+            q = q.checked_mul(&period)?;
+        };
+
+        if !self.exponent.is_empty() && p != T::Unsigned::zero() {
+            let exponent = self
+                .exponent
+                .chars()
+                .filter(|char| char.is_ascii_digit())
+                .collect::<String>()
+                .parse::<usize>()
+                .ok()?;
+            let scale = checked_power::<T::Unsigned>(10, exponent)?;
+            if self.exponent_negative {
+                q = q.checked_mul(&scale)?;
+            } else {
+                p = p.checked_mul(&scale)?;
+            };
+        };
+
+        to_signed(self.negative, p, q)
+    }
+
+    fn to_big(
+        &self,
+        radix: u32,
+        max_digits: Option<usize>,
+    ) -> Result<BigRational, ParseRationalError> {
+        let overflow = ParseRationalError::new(RationalErrorKind::Overflow, self.position);
+
+        let p = parse_digits([self.integral, self.fractional].concat().as_str(), radix);
+        let q = power(radix, digit_count(self.fractional)).ok_or(overflow.clone())?;
+
+        let mut value = Ratio::new(BigInt::from_unsigned(p), BigInt::from_unsigned(q.clone()));
+        if !self.repeating.is_empty() {
+            let repeating_p = parse_digits(self.repeating, radix);
+            let repeating_q =
+                power(radix, digit_count(self.repeating)).ok_or(overflow.clone())? - BigUint::one();
+            value += Ratio::new(
+                BigInt::from_unsigned(repeating_p),
+                BigInt::from_unsigned(repeating_q * q),
+            );
+        };
+
+        if !self.exponent.is_empty() && !value.numerator().is_zero() {
+            let exponent = self
+                .exponent
+                .chars()
+                .filter(|char| char.is_ascii_digit())
+                .collect::<String>()
+                .parse::<u32>()
+                .ok()
+                .filter(|exponent| *exponent <= self.max_exponent(max_digits))
+                .ok_or(overflow.clone())?;
+            let scale = Ratio::from(BigInt::from_unsigned(
+                power(10, exponent as usize).ok_or(overflow)?,
+            ));
+            value = if self.exponent_negative {
+                value / scale
+            } else {
+                value * scale
+            };
+        };
+
+        Ok(if self.negative { -value } else { value })
+    }
+
+    /// Largest exponent that can still give a value between 10^-max_digits and 10^max_digits,
+    /// so huge exponents are rejected before calculating the power of ten.
+    fn max_exponent(&self, max_digits: Option<usize>) -> u32 {
+        let Some(max_digits) = max_digits else {
+            return MAX_UNBOUNDED_EXPONENT;
+        };
+        // A nonzero value is at least 10^-(fractional + repeating digits) and at most 10^(integral digits)
+        let bound = if self.exponent_negative {
+            max_digits + digit_count(self.integral) + 1
+        } else {
+            max_digits + digit_count(self.fractional) + digit_count(self.repeating)
+        };
+        u32::try_from(bound).unwrap_or(u32::MAX)
+    }
+}
+
+/// Largest exponent accepted when parsing into unbounded integer types,
+/// which keeps a short string from describing a number too big to calculate.
+const MAX_UNBOUNDED_EXPONENT: u32 = 100_000;
+
+/// The parts of a fraction or a mixed number, as sequences of digits.
+struct Fraction<'a> {
+    /// Byte offset of the start of the number.
+    position: usize,
+    /// Combined sign of the numerator and the denominator.
+    negative: bool,
+    /// Integer part of a mixed number, empty for plain fractions.
+    whole: &'a str,
+    numerator: &'a str,
+    denominator: &'a str,
+    denominator_position: usize,
+}
+
+impl Fraction<'_> {
+    /// Calculates the value with the unsigned counterpart of `T`, None when anything overflows
+    /// or the denominator is zero.
+    fn to_fixed_width<T: Integer>(&self, radix: u32) -> Option<Ratio<T>> {
+        let q = accumulate_digits(T::Unsigned::zero(), self.denominator, radix)?;
+        if q == T::Unsigned::zero() {
+            return None;
+        };
+        let numerator = accumulate_digits(T::Unsigned::zero(), self.numerator, radix)?;
+        let p = accumulate_digits(T::Unsigned::zero(), self.whole, radix)?
+            .checked_mul(&q)?
+            .checked_add(&numerator)?;
+        to_signed(self.negative, p, q)
+    }
+
+    fn to_big(&self, radix: u32) -> Result<BigRational, ParseRationalError> {
+        let q = parse_digits(self.denominator, radix);
+        if q.is_zero() {
+            return Err(ParseRationalError::new(
+                RationalErrorKind::ZeroDenominator,
+                self.denominator_position,
+            ));
+        };
+        let p = parse_digits(self.whole, radix) * q.clone() + parse_digits(self.numerator, radix);
+
+        let value = Ratio::new(BigInt::from_unsigned(p), BigInt::from_unsigned(q));
+        Ok(if self.negative { -value } else { value })
+    }
+}
+
+fn power(radix: u32, exponent: usize) -> Option<BigUint> {
+    Some(BigUint::from(radix).pow(u32::try_from(exponent).ok()?))
+}
+
+/// Appends a sequence of digits to `value`, skipping the separators. None on overflow.
+fn accumulate_digits<U: Unsigned>(value: U, digits: &str, radix: u32) -> Option<U> {
+    let base = U::from_u8(radix as u8);
+    digits
+        .chars()
+        .filter_map(|char| char.to_digit(radix))
+        .try_fold(value, |value, digit| {
+            value
+                .checked_mul(&base)?
+                .checked_add(&U::from_u8(digit as u8))
+        })
+}
+
+/// Calculates radix^exponent, None on overflow.
+fn checked_power<U: Unsigned>(radix: u32, exponent: usize) -> Option<U> {
+    let base = U::from_u8(radix as u8);
+    // Overflows after a few steps at most, unless the type is unbounded
+    (0..exponent).try_fold(U::one(), |value, _| value.checked_mul(&base))
+}
+
+/// Reduces the magnitudes p / q and applies the sign, None when the result doesn't fit into `T`.
+fn to_signed<T: Integer>(negative: bool, p: T::Unsigned, q: T::Unsigned) -> Option<Ratio<T>> {
+    let divisor = gcd(p.clone(), q.clone());
+    let (p, q) = (p / divisor.clone(), q / divisor);
+
+    // Converting wraps around, so a magnitude which doesn't fit comes back different
+    let (signed_p, signed_q) = (T::from_unsigned(p.clone()), T::from_unsigned(q));
+    if signed_p.unsigned_abs() != p || signed_q <= T::zero() {
+        return None;
+    };
+    let p = match (negative, signed_p >= T::zero()) {
+        (false, true) => signed_p,
+        // The magnitude of T::MIN wraps around to T::MIN itself
+        (false, false) => return None,
+        (true, true) => -signed_p,
+        (true, false) => signed_p,
+    };
+    Some(Ratio::new_unchecked(p, signed_q))
+}
+
+/// Calculates the value of a sequence of digits, skipping the separators.
+fn parse_digits(digits: &str, radix: u32) -> BigUint {
+    BigUint::from_digits(
+        digits.chars().filter_map(|char| char.to_digit(radix)),
+        radix,
+    )
+}
+
+/// Number of digits in a sequence of digits and separators.
+fn digit_count(digits: &str) -> usize {
+    digits
+        .chars()
+        .filter(|char| char.is_ascii_alphanumeric())
+        .count()
+}
+
+/// A cursor over the string being parsed, keeping track of the byte offset for errors.
+struct Parser<'a> {
+    text: &'a str,
+    position: usize,
+    radix: u32,
+    options: ParseOptions,
+}
+
+impl<'a> Parser<'a> {
+    fn new(text: &'a str, radix: u32, options: ParseOptions) -> Parser<'a> {
+        Parser {
+            text,
+            position: 0,
+            radix,
+            options,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.text[self.position..].chars().next()
+    }
+
+    /// Consumes `expected` if the rest of the string starts with it.
+    fn eat_str(&mut self, expected: &str) -> bool {
+        if self.text[self.position..].starts_with(expected) {
+            self.position += expected.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Consumes `expected` if it is the next character.
+    fn eat(&mut self, expected: char) -> bool {
+        if self.peek() == Some(expected) {
+            self.position += expected.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Consumes a possibly empty sequence of digits in the radix of the parser,
+    /// with the separators allowed by the options between them.
+    fn digits(&mut self) -> &'a str {
+        let start = self.position;
+        let is_digit = |char: Option<char>| char.is_some_and(|char| char.is_digit(self.radix));
+        loop {
+            match self.peek() {
+                char if is_digit(char) => self.position += 1,
+                Some(char)
+                    if self.options.is_separator(char)
+                        && self.position > start
+                        && is_digit(self.text[self.position + 1..].chars().next()) =>
+                {
+                    self.position += 1
+                }
+                _ => break,
+            }
+        }
+        &self.text[start..self.position]
+    }
+
+    /// Consumes a sign, returning whether it is negative. Only a minus is accepted unless the options allow more.
+    fn parse_sign(&mut self) -> bool {
+        if self.eat('-') || self.options.unicode_minus && self.eat('\u{2212}') {
+            return true;
+        };
+        if self.options.plus_sign {
+            self.eat('+');
+        };
+        false
+    }
+
+    fn error(&self, kind: RationalErrorKind) -> ParseRationalError {
+        ParseRationalError::new(kind, self.position)
+    }
+
+    /// The error for a sequence of digits missing at the current position.
+    fn missing_digits(&self) -> ParseRationalError {
+        match self.peek() {
+            Some(_) => self.error(RationalErrorKind::InvalidChar),
+            None => self.error(RationalErrorKind::Empty),
+        }
+    }
+
+    fn expect_symbol(&mut self, symbol: char) -> Result<(), ParseRationalError> {
+        if self.eat(symbol) {
+            return Ok(());
+        };
+        match self.peek() {
+            Some(_) => Err(self.error(RationalErrorKind::InvalidChar)),
+            None => Err(self.error(RationalErrorKind::MissingSymbol)),
+        }
+    }
+
+    fn expect_end(&self) -> Result<(), ParseRationalError> {
+        match self.peek() {
+            None => Ok(()),
+            Some(_) => Err(self.error(RationalErrorKind::InvalidChar)),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(char) = self.peek().filter(|char| char.is_whitespace()) {
+            self.position += char.len_utf8();
+        }
+    }
+
+    fn parse_literal(&mut self) -> Result<Literal<'a>, ParseRationalError> {
+        let start = self.position;
+        let negative = self.parse_sign();
+        let integral = self.digits();
+
+        if !integral.is_empty() {
+            if self.eat('/') {
+                return self.parse_denominator(start, negative, "", integral);
+            };
+            if self.peek().is_some_and(|char| char.is_whitespace()) {
+                let whitespace = self.position;
+                self.skip_whitespace();
+                let numerator = self.digits();
+                if !numerator.is_empty() {
+                    if !self.eat('/') {
+                        return Err(self.error(RationalErrorKind::InvalidChar));
+                    };
+                    return self.parse_denominator(start, negative, integral, numerator);
+                };
+                // The whitespace is either trailing, or not allowed at all
+                self.position = whitespace;
+                if !(self.options.whitespace && self.text[whitespace..].trim().is_empty()) {
+                    return Err(self.error(RationalErrorKind::InvalidChar));
+                };
+            };
+        };
+
+        self.parse_decimal(start, negative, integral)
+            .map(Literal::Decimal)
+    }
+
+    /// Parses the denominator after the `/`. Only plain fractions allow a negative denominator.
+    fn parse_denominator(
+        &mut self,
+        start: usize,
+        negative: bool,
+        whole: &'a str,
+        numerator: &'a str,
+    ) -> Result<Literal<'a>, ParseRationalError> {
+        let denominator_position = self.position;
+        let denominator_negative = whole.is_empty() && self.parse_sign();
+        let denominator = self.digits();
+        if denominator.is_empty() {
+            return Err(self.missing_digits());
+        };
+
+        Ok(Literal::Fraction(Fraction {
+            position: start,
+            negative: negative != denominator_negative,
+            whole,
+            numerator,
+            denominator,
+            denominator_position,
+        }))
+    }
+
+    /// Parses the rest of a decimal after its sign and integral part.
+    fn parse_decimal(
+        &mut self,
+        start: usize,
+        negative: bool,
+        integral: &'a str,
+    ) -> Result<Decimal<'a>, ParseRationalError> {
+        let (mut fractional, mut repeating) = ("", "");
+        if self.eat('.') {
+            fractional = self.digits();
+            repeating = self.parse_repeating(&mut fractional)?;
+        };
+
+        if integral.is_empty() && fractional.is_empty() && repeating.is_empty() {
+            return match self.peek() {
+                Some(_) => Err(self.error(RationalErrorKind::InvalidChar)),
+                None => Err(ParseRationalError::new(RationalErrorKind::Empty, start)),
+            };
+        };
+
+        let (exponent_negative, exponent) = self.parse_exponent()?;
+
+        Ok(Decimal {
+            position: start,
+            negative,
+            integral,
+            fractional,
+            repeating,
+            exponent_negative,
+            exponent,
+        })
+    }
+
+    /// Parses the optional exponent of the scientific notation, e.g. `e-7`.
+    fn parse_exponent(&mut self) -> Result<(bool, &'a str), ParseRationalError> {
+        if self.radix != 10 || !self.eat('e') && !self.eat('E') {
+            return Ok((false, ""));
+        };
+
+        let negative = self.parse_sign();
+        if !negative {
+            self.eat('+');
+        };
+        let exponent = self.digits();
+        if exponent.is_empty() {
+            return Err(self.missing_digits());
+        };
+
+        Ok((negative, exponent))
+    }
+
+    /// Parses the optional repeating part after the fractional digits, written in one of the notations
+    /// `0.1(6)`, `0.1[6]`, `0.16̅` or `0.166...`.
+    ///
+    /// The overline and the ellipsis notations take the repeating digits from the end of the fractional part,
+    /// which is shortened accordingly.
+    fn parse_repeating(&mut self, fractional: &mut &'a str) -> Result<&'a str, ParseRationalError> {
+        let start = self.position;
+        if self.peek() == Some(OVERLINE) && !fractional.is_empty() {
+            return Ok(self.parse_overline(fractional));
+        };
+        if self.eat_str("...") || self.eat('…') {
+            let period_start = infer_period(fractional).ok_or(ParseRationalError::new(
+                RationalErrorKind::UnknownPeriod,
+                start,
+            ))?;
+            let repeating = &fractional[period_start..];
+            *fractional = &fractional[..period_start];
+            return Ok(repeating);
+        };
+
+        let closing = if self.eat('(') {
+            ')'
+        } else if self.eat('[') {
+            ']'
+        } else {
+            return Ok("");
+        };
+
+        let repeating = self.digits();
+        if repeating.is_empty() && self.peek() == Some(closing) {
+            return Err(ParseRationalError::new(
+                RationalErrorKind::EmptyRepeating,
+                start,
+            ));
+        };
+
+        match self.peek() {
+            Some(char) if char == closing => {
+                self.position += 1;
+                Ok(repeating)
+            }
+            Some(_) => Err(self.error(RationalErrorKind::InvalidChar)),
+            None => Err(ParseRationalError::new(
+                RationalErrorKind::UnclosedRepeating,
+                start,
+            )),
+        }
+    }
+
+    /// Parses the overlined digits, starting with the last fractional digit, which the parser just passed.
+    fn parse_overline(&mut self, fractional: &mut &'a str) -> &'a str {
+        let start = self.position - 1;
+        *fractional = &fractional[..fractional.len() - 1];
+        self.position += OVERLINE.len_utf8();
+
+        while self.peek().is_some_and(|char| char.is_digit(self.radix))
+            && self.text[self.position + 1..].starts_with(OVERLINE)
+        {
+            self.position += 1 + OVERLINE.len_utf8();
+        }
+        &self.text[start..self.position]
+    }
+}
+
+/// Combining overline, marking the digit before it as repeating.
+const OVERLINE: char = '\u{305}';
+
+/// Infers where the period starts in the fractional digits of a decimal written with an ellipsis,
+/// returning the byte offset in the digits.
+///
+/// For every period length, the longest tail of the digits repeating with that period is found,
+/// and it has to contain the period at least twice. The period with the longest tail is chosen,
+/// the shortest one among equally long tails.
+fn infer_period(fractional: &str) -> Option<usize> {
+    let digits: Vec<(usize, char)> = fractional
+        .char_indices()
+        .filter(|(_, char)| char.is_ascii_alphanumeric())
+        .map(|(index, char)| (index, char.to_ascii_lowercase()))
+        .collect();
+    let length = digits.len();
+
+    let mut best: Option<(usize, usize)> = None;
+    for period in 1..=length / 2 {
+        let mut tail = period;
+        while tail < length && digits[length - 1 - tail].1 == digits[length - 1 - tail + period].1 {
+            tail += 1;
+        }
+        if tail >= 2 * period && best.is_none_or(|(best_tail, _)| tail > best_tail) {
+            best = Some((tail, period));
+        };
+    }
+    best.map(|(_, period)| digits[length - period].0)
+}
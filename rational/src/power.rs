@@ -0,0 +1,107 @@
+//! Integer and rational powers.
+
+use std::cmp::Ordering;
+
+use crate::{Integer, Ratio, Unsigned};
+
+impl<T: Integer> Ratio<T> {
+    /// Raises self to an integer power, a negative exponent gives the power of the reciprocal.
+    ///
+    /// ```
+    /// # use rational::*;
+    /// assert_eq!(Rational::new(2, 3).pow(3), Rational::new(8, 27));
+    /// assert_eq!(Rational::new(2, 3).pow(-2), Rational::new(9, 4));
+    /// assert_eq!(Rational::new(-1, 2).pow(0), Rational::new(1, 1));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if zero is raised to a negative power, or if the result can't be represented.
+    pub fn pow(self, exp: i32) -> Self {
+        if exp < 0 && self.p == T::zero() {
+            panic!("Can't divide by zero")
+        };
+        self.checked_pow(exp)
+            .expect("attempt to multiply with overflow")
+    }
+
+    /// Raises self to an integer power, returning None if the result can't be represented or
+    /// zero is raised to a negative power.
+    ///
+    /// ```
+    /// # use rational::*;
+    /// assert_eq!(Ratio::<i8>::new(-2, 5).checked_pow(3), Some(Ratio::new(-8, 125)));
+    /// assert_eq!(Ratio::<i8>::new(2, 5).checked_pow(4), None);
+    /// assert_eq!(Ratio::<i8>::new(0, 1).checked_pow(-1), None);
+    /// ```
+    pub fn checked_pow(self, exp: i32) -> Option<Self> {
+        let base = if exp < 0 { self.checked_recip()? } else { self };
+        // The powers of coprime numbers are coprime, so the result is already reduced
+        Some(Ratio {
+            p: checked_pow_integer(&base.p, exp.unsigned_abs())?,
+            q: checked_pow_integer(&base.q, exp.unsigned_abs())?,
+        })
+    }
+
+    /// Raises self to a rational power, returning None unless the result is rational and can be
+    /// represented.
+    ///
+    /// The power `a/b` is calculated as the `a`-th power of the `b`-th root, so it only exists
+    /// when self is a perfect `b`-th power of a rational. Odd roots of negative values are
+    /// negative, while even roots of them don't exist.
+    ///
+    /// ```
+    /// # use rational::*;
+    /// let value = Rational::new(8, 27);
+    /// assert_eq!(value.try_pow_rational(Rational::new(2, 3)), Some(Rational::new(4, 9)));
+    /// assert_eq!(value.try_pow_rational(Rational::new(-1, 3)), Some(Rational::new(3, 2)));
+    /// assert_eq!(value.try_pow_rational(Rational::new(1, 2)), None);
+    /// assert_eq!(Rational::new(-8, 1).try_pow_rational(Rational::new(1, 3)), Some(Rational::new(-2, 1)));
+    /// ```
+    pub fn try_pow_rational(self, exp: Ratio<T>) -> Option<Self> {
+        if self.q == T::one() && self.p.unsigned_abs() <= T::Unsigned::one() {
+            return self.trivial_pow_rational(exp);
+        };
+
+        // Any other base grows without bound, so larger degrees or exponents can't be exact
+        let degree = u32::try_from(exp.q.to_big_int()).ok()?;
+        let exp = i32::try_from(exp.p.to_big_int()).ok()?;
+        self.nth_root_exact(degree)?.checked_pow(exp)
+    }
+
+    /// Rational powers of -1, 0 and 1, which stay trivial for arbitrarily large exponents.
+    fn trivial_pow_rational(self, exp: Ratio<T>) -> Option<Self> {
+        let odd = |value: &T| !value.unsigned_abs().is_even();
+        if self.p == T::zero() {
+            return match exp.p.cmp(&T::zero()) {
+                Ordering::Less => None,
+                Ordering::Equal => Some(Ratio::from(T::one())),
+                Ordering::Greater => Some(self),
+            };
+        };
+        if self.p < T::zero() && !odd(&exp.q) {
+            return None;
+        };
+        if self.p < T::zero() && odd(&exp.p) {
+            return Some(self);
+        };
+        Some(Ratio::from(T::one()))
+    }
+}
+
+/// Exponentiation by squaring, None on overflow.
+fn checked_pow_integer<T: Integer>(base: &T, mut exp: u32) -> Option<T> {
+    let mut result = T::one();
+    let mut base = base.clone();
+    loop {
+        if exp % 2 == 1 {
+            result = result.checked_mul(&base)?;
+        };
+        exp /= 2;
+        if exp == 0 {
+            return Some(result);
+        };
+        // Only squared when a higher bit needs it, so it never exceeds the result
+        base = base.checked_mul(&base)?;
+    }
+}
@@ -0,0 +1,136 @@
+//! Exact roots and rational approximations of square roots.
+
+use crate::approximation::{best_sqrt_approximation, sqrt_convergents};
+use crate::{BigInt, Integer, Ratio, Unsigned};
+
+impl<T: Integer> Ratio<T> {
+    /// Returns the square root if it's a rational number.
+    ///
+    /// Negative values have no square root.
+    ///
+    /// ```
+    /// # use rational::*;
+    /// assert_eq!(Rational::new(9, 4).sqrt_exact(), Some(Rational::new(3, 2)));
+    /// assert_eq!(Rational::new(2, 1).sqrt_exact(), None);
+    /// assert_eq!(Rational::new(-4, 1).sqrt_exact(), None);
+    /// ```
+    pub fn sqrt_exact(&self) -> Option<Self> {
+        self.nth_root_exact(2)
+    }
+
+    /// Returns the `n`-th root if it's a rational number.
+    ///
+    /// Odd roots of negative values are negative, while even roots of them don't exist.
+    ///
+    /// ```
+    /// # use rational::*;
+    /// assert_eq!(Rational::new(-8, 27).nth_root_exact(3), Some(Rational::new(-2, 3)));
+    /// assert_eq!(Rational::new(16, 81).nth_root_exact(4), Some(Rational::new(2, 3)));
+    /// assert_eq!(Rational::new(16, 81).nth_root_exact(3), None);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    pub fn nth_root_exact(&self, n: u32) -> Option<Self> {
+        assert!(n > 0, "attempt to take a root of degree zero");
+        let negative = self.p < T::zero();
+        if negative && n.is_multiple_of(2) {
+            return None;
+        };
+
+        // The numerator and the denominator are coprime, so both have to be perfect powers
+        let root = |value: &T| {
+            let value = value.to_big_int();
+            let root = value.magnitude().nth_root(n);
+            if root.clone().pow(n) != *value.magnitude() {
+                return None;
+            };
+            let root = BigInt::from_unsigned(root);
+            // The root is never larger than the value itself
+            T::from_big_int(&if value.is_negative() { -root } else { root })
+        };
+        Some(Ratio {
+            p: root(&self.p)?,
+            q: root(&self.q)?,
+        })
+    }
+
+    /// Finds the fraction closest to the square root with a denominator of at most
+    /// `max_denominator`, None for negative values.
+    ///
+    /// The search walks the continued fraction of the root, which is calculated exactly, so the
+    /// result is the best approximation rather than a rounded float. When the best fraction has a
+    /// numerator which doesn't fit into `T`, the closest one which does is returned instead.
+    ///
+    /// ```
+    /// # use rational::*;
+    /// let two = Ratio::<i64>::new(2, 1);
+    /// assert_eq!(two.sqrt_approx(10), Some(Ratio::new(7, 5)));
+    /// assert_eq!(two.sqrt_approx(1000), Some(Ratio::new(1393, 985)));
+    /// assert_eq!(Ratio::<i64>::new(9, 4).sqrt_approx(10), Some(Ratio::new(3, 2)));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_denominator` is less than 1.
+    pub fn sqrt_approx(&self, max_denominator: T) -> Option<Self> {
+        assert!(
+            max_denominator >= T::one(),
+            "max_denominator must be at least 1"
+        );
+        if self.p < T::zero() {
+            return None;
+        };
+        if let Some(root) = self.sqrt_exact() {
+            return Some(root.limit_denominator(max_denominator));
+        };
+
+        let max_numerator = T::bounds().map(|(_, max)| max.to_big_int());
+        let approximation = best_sqrt_approximation(
+            &self.to_big(),
+            max_numerator.as_ref(),
+            &max_denominator.to_big_int(),
+        );
+        Some(Ratio::from_big(approximation).expect("Approximation is within bounds"))
+    }
+
+    /// Finds a fraction within `epsilon` of the square root, None for negative values or when
+    /// such a fraction can't be represented.
+    ///
+    /// Returns the exact root when there is one, and otherwise the first continued fraction
+    /// convergent that is close enough, which has the smallest denominator among them.
+    ///
+    /// ```
+    /// # use rational::*;
+    /// let two = Ratio::<i64>::new(2, 1);
+    /// assert_eq!(two.sqrt_within(Ratio::new(1, 100)), Some(Ratio::new(17, 12)));
+    /// assert_eq!(two.sqrt_within(Ratio::new(1, 1000000)), Some(Ratio::new(1393, 985)));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `epsilon` isn't positive.
+    pub fn sqrt_within(&self, epsilon: Self) -> Option<Self> {
+        assert!(epsilon.p > T::zero(), "epsilon must be positive");
+        if self.p < T::zero() {
+            return None;
+        };
+        if let Some(root) = self.sqrt_exact() {
+            return Some(root);
+        };
+
+        let (value, epsilon) = (self.to_big(), epsilon.to_big());
+        let zero = Ratio::from(BigInt::zero());
+        // |c - sqrt(value)| <= epsilon exactly when value lies between (c - epsilon)^2 and (c + epsilon)^2
+        let within = |convergent: &Ratio<BigInt>| {
+            let lower = convergent.clone() - epsilon.clone();
+            let upper = convergent.clone() + epsilon.clone();
+            (lower <= zero || lower.clone() * lower <= value) && value <= upper.clone() * upper
+        };
+        let convergent = sqrt_convergents(&value)
+            .find(within)
+            .expect("Convergents approach the root");
+        Ratio::from_big(convergent)
+    }
+}
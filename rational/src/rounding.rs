@@ -0,0 +1,340 @@
+//! Rounding to integers and conversions into integer types.
+
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use crate::{BigInt, Integer, Ratio, TryFromBigIntError, Unsigned};
+
+/// How to round a value which lies exactly halfway between two integers.
+///
+/// Values which are closer to one of the integers are always rounded to that integer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RoundingMode {
+    /// Rounds halves towards positive infinity, so 2.5 becomes 3 and -2.5 becomes -2.
+    HalfUp,
+    /// Rounds halves towards negative infinity, so 2.5 becomes 2 and -2.5 becomes -3.
+    HalfDown,
+    /// Rounds halves to the even neighbour, so 2.5 becomes 2 and 3.5 becomes 4.
+    HalfEven,
+    /// Rounds halves away from zero, so 2.5 becomes 3 and -2.5 becomes -3.
+    #[default]
+    HalfAwayFromZero,
+}
+
+/// The error returned when a rational can't be converted into an integer type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TryFromRationalError(RationalErrorCause);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RationalErrorCause {
+    NotInteger,
+    OutOfRange,
+}
+
+impl<T: Integer> Ratio<T> {
+    /// Returns the largest integer less than or equal to the value.
+    ///
+    /// ```
+    /// # use rational::*;
+    /// assert_eq!(Rational::new(7, 2).floor(), 3);
+    /// assert_eq!(Rational::new(-7, 2).floor(), -4);
+    /// ```
+    pub fn floor(&self) -> T {
+        let (quotient, remainder) = self.trunc_rem();
+        if remainder < T::zero() {
+            quotient - T::one()
+        } else {
+            quotient
+        }
+    }
+
+    /// Returns the smallest integer greater than or equal to the value.
+    ///
+    /// ```
+    /// # use rational::*;
+    /// assert_eq!(Rational::new(7, 2).ceil(), 4);
+    /// assert_eq!(Rational::new(-7, 2).ceil(), -3);
+    /// ```
+    pub fn ceil(&self) -> T {
+        let (quotient, remainder) = self.trunc_rem();
+        if remainder > T::zero() {
+            quotient + T::one()
+        } else {
+            quotient
+        }
+    }
+
+    /// Returns the integer part of the value, rounding towards zero.
+    ///
+    /// ```
+    /// # use rational::*;
+    /// assert_eq!(Rational::new(7, 2).trunc(), 3);
+    /// assert_eq!(Rational::new(-7, 2).trunc(), -3);
+    /// ```
+    pub fn trunc(&self) -> T {
+        self.p.clone() / self.q.clone()
+    }
+
+    /// Rounds to the nearest integer, rounding halves away from zero like [f64::round].
+    ///
+    /// ```
+    /// # use rational::*;
+    /// assert_eq!(Rational::new(5, 2).round(), 3);
+    /// assert_eq!(Rational::new(-5, 2).round(), -3);
+    /// assert_eq!(Rational::new(-7, 3).round(), -2);
+    /// ```
+    pub fn round(&self) -> T {
+        self.round_with(RoundingMode::HalfAwayFromZero)
+    }
+
+    /// Rounds to the nearest integer, resolving halves with the given mode.
+    ///
+    /// ```
+    /// # use rational::*;
+    /// let half = Rational::new(-5, 2);
+    /// assert_eq!(half.round_with(RoundingMode::HalfUp), -2);
+    /// assert_eq!(half.round_with(RoundingMode::HalfDown), -3);
+    /// assert_eq!(half.round_with(RoundingMode::HalfEven), -2);
+    /// assert_eq!(half.round_with(RoundingMode::HalfAwayFromZero), -3);
+    /// ```
+    pub fn round_with(&self, mode: RoundingMode) -> T {
+        let (quotient, remainder) = self.trunc_rem();
+        let remainder = remainder.unsigned_abs();
+        let negative = self.p < T::zero();
+
+        // Compares the fractional part with 1/2 without overflowing
+        let away_from_zero = match remainder.cmp(&(self.q.unsigned_abs() - remainder.clone())) {
+            Ordering::Less => false,
+            Ordering::Greater => true,
+            Ordering::Equal => match mode {
+                RoundingMode::HalfUp => !negative,
+                RoundingMode::HalfDown => negative,
+                RoundingMode::HalfEven => !quotient.unsigned_abs().is_even(),
+                RoundingMode::HalfAwayFromZero => true,
+            },
+        };
+
+        match (away_from_zero, negative) {
+            (false, _) => quotient,
+            (true, false) => quotient + T::one(),
+            (true, true) => quotient - T::one(),
+        }
+    }
+
+    /// Returns the fractional part, which has the same sign as the value.
+    ///
+    /// The value is always equal to `trunc() + fract()`.
+    ///
+    /// ```
+    /// # use rational::*;
+    /// assert_eq!(Rational::new(7, 2).fract(), Rational::new(1, 2));
+    /// assert_eq!(Rational::new(-7, 2).fract(), Rational::new(-1, 2));
+    /// assert_eq!(Rational::new(4, 1).fract(), Rational::new(0, 1));
+    /// ```
+    pub fn fract(&self) -> Self {
+        let (_, remainder) = self.trunc_rem();
+        if remainder == T::zero() {
+            Ratio::new_unchecked(remainder, T::one())
+        } else {
+            // The remainder is coprime with the denominator, like the numerator
+            Ratio::new_unchecked(remainder, self.q.clone())
+        }
+    }
+
+    /// Divides by `rhs`, returning the quotient rounded towards zero and the remainder.
+    ///
+    /// Works like `/` and `%` on primitive integers: the remainder has the sign of `self`, its
+    /// magnitude is less than that of `rhs`, and `self == rhs * quotient + remainder`.
+    ///
+    /// ```
+    /// # use rational::*;
+    /// let turn = Rational::new(1, 1);
+    /// assert_eq!(Rational::new(9, 4).div_rem(turn), (2, Rational::new(1, 4)));
+    /// assert_eq!(Rational::new(-9, 4).div_rem(turn), (-2, Rational::new(-1, 4)));
+    /// assert_eq!(Rational::new(9, 4).div_rem(-turn), (-2, Rational::new(1, 4)));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero, or if the quotient or the remainder can't be represented.
+    pub fn div_rem(self, rhs: Self) -> (T, Self) {
+        if rhs.p == T::zero() {
+            panic!("Can't divide by zero")
+        };
+        self.checked_div_rem_ref(&rhs)
+            .expect("attempt to calculate the remainder with overflow")
+    }
+
+    /// Calculates the quotient of Euclidean division, the integer `n` for which
+    /// `self == rhs * n + self.rem_euclid(rhs)`.
+    ///
+    /// This is the floor of `self / rhs` for a positive `rhs`, and the ceiling for a negative one.
+    ///
+    /// ```
+    /// # use rational::*;
+    /// assert_eq!(Rational::new(7, 2).div_euclid(Rational::new(3, 2)), 2);
+    /// assert_eq!(Rational::new(-7, 2).div_euclid(Rational::new(3, 2)), -3);
+    /// assert_eq!(Rational::new(7, 2).div_euclid(Rational::new(-3, 2)), -2);
+    /// assert_eq!(Rational::new(-7, 2).div_euclid(Rational::new(-3, 2)), 3);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero, or if the quotient or the remainder can't be represented.
+    pub fn div_euclid(self, rhs: Self) -> T {
+        if rhs.p == T::zero() {
+            panic!("Can't divide by zero")
+        };
+        let (quotient, _) = self
+            .checked_div_rem_euclid_ref(&rhs)
+            .expect("attempt to calculate the remainder with overflow");
+        quotient
+    }
+
+    /// Calculates the least non-negative remainder of `self` modulo `rhs`.
+    ///
+    /// The result is always in the range `[0, |rhs|)`, which makes it suitable for wrapping
+    /// periodic values such as angles. Unlike [div_euclid](Ratio::div_euclid) it works even
+    /// when the quotient doesn't fit into `T`.
+    ///
+    /// ```
+    /// # use rational::*;
+    /// assert_eq!(Rational::new(7, 2).rem_euclid(Rational::new(3, 2)), Rational::new(1, 2));
+    /// assert_eq!(Rational::new(-7, 2).rem_euclid(Rational::new(3, 2)), Rational::new(1, 1));
+    /// assert_eq!(Rational::new(-7, 2).rem_euclid(Rational::new(-3, 2)), Rational::new(1, 1));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero, or if the remainder can't be represented.
+    pub fn rem_euclid(self, rhs: Self) -> Self {
+        if rhs.p == T::zero() {
+            panic!("Can't divide by zero")
+        };
+        self.checked_rem_euclid_ref(&rhs)
+            .expect("attempt to calculate the remainder with overflow")
+    }
+
+    /// Euclidean division with remainder, None when a result doesn't fit or `rhs` is zero.
+    fn checked_div_rem_euclid_ref(&self, rhs: &Self) -> Option<(T, Self)> {
+        // The truncating remainder may overflow even when the Euclidean one fits
+        self.fixed_width_div_rem_euclid(rhs).or_else(|| {
+            let (quotient, remainder) = self.to_big().fixed_width_div_rem_euclid(&rhs.to_big())?;
+            Some((T::from_big_int(&quotient)?, Ratio::from_big(remainder)?))
+        })
+    }
+
+    /// Euclidean remainder, None when it doesn't fit or `rhs` is zero.
+    fn checked_rem_euclid_ref(&self, rhs: &Self) -> Option<Self> {
+        match self.fixed_width_div_rem_euclid(rhs) {
+            Some((_, remainder)) => Some(remainder),
+            None => {
+                let (_, remainder) = self.to_big().fixed_width_div_rem_euclid(&rhs.to_big())?;
+                Ratio::from_big(remainder)
+            }
+        }
+    }
+
+    /// Euclidean division with remainder, None when an intermediate value overflows.
+    fn fixed_width_div_rem_euclid(&self, rhs: &Self) -> Option<(T, Self)> {
+        let (quotient, remainder) = self.fixed_width_div_rem(rhs)?;
+        if remainder.p >= T::zero() {
+            Some((quotient, remainder))
+        } else if rhs.p > T::zero() {
+            Some((
+                quotient.checked_sub(&T::one())?,
+                remainder.checked_add_ref(rhs)?,
+            ))
+        } else {
+            Some((
+                quotient.checked_add(&T::one())?,
+                remainder.checked_sub_ref(rhs)?,
+            ))
+        }
+    }
+
+    /// Truncating division with remainder, None when a result doesn't fit or `rhs` is zero.
+    pub(crate) fn checked_div_rem_ref(&self, rhs: &Self) -> Option<(T, Self)> {
+        // The final results may fit even when the exact quotient doesn't
+        self.fixed_width_div_rem(rhs).or_else(|| {
+            let (quotient, remainder) = self.to_big().checked_div_rem_ref(&rhs.to_big())?;
+            Some((T::from_big_int(&quotient)?, Ratio::from_big(remainder)?))
+        })
+    }
+
+    /// Truncating remainder, None when it doesn't fit or `rhs` is zero.
+    ///
+    /// Unlike [checked_div_rem_ref](Ratio::checked_div_rem_ref) the integer quotient doesn't
+    /// need to fit into `T`.
+    pub(crate) fn checked_rem_ref(&self, rhs: &Self) -> Option<Self> {
+        match self.fixed_width_div_rem(rhs) {
+            Some((_, remainder)) => Some(remainder),
+            None => {
+                let (_, remainder) = self.to_big().checked_div_rem_ref(&rhs.to_big())?;
+                Ratio::from_big(remainder)
+            }
+        }
+    }
+
+    /// Truncating division with remainder, None when an intermediate value overflows.
+    fn fixed_width_div_rem(&self, rhs: &Self) -> Option<(T, Self)> {
+        let quotient = self.checked_div_ref(rhs)?.trunc();
+        let product = rhs.checked_mul_ref(&Ratio::from(quotient.clone()))?;
+        Some((quotient, self.checked_sub_ref(&product)?))
+    }
+
+    /// Divides the numerator by the denominator, rounding towards zero.
+    fn trunc_rem(&self) -> (T, T) {
+        (
+            self.p.clone() / self.q.clone(),
+            self.p.clone() % self.q.clone(),
+        )
+    }
+}
+
+macro_rules! impl_try_into_integer {
+    ($($int:ty),*) => {$(
+        /// Converts an integral rational into an integer, failing when it has a fractional part
+        /// or doesn't fit into the target type.
+        impl<T: Integer> TryFrom<Ratio<T>> for $int {
+            type Error = TryFromRationalError;
+
+            fn try_from(value: Ratio<T>) -> Result<Self, Self::Error> {
+                let integer = BigInt::try_from(value)?;
+                <$int>::try_from(integer).map_err(TryFromRationalError::from)
+            }
+        }
+    )*};
+}
+
+impl_try_into_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// Converts an integral rational into an integer, failing when it has a fractional part.
+impl<T: Integer> TryFrom<Ratio<T>> for BigInt {
+    type Error = TryFromRationalError;
+
+    fn try_from(value: Ratio<T>) -> Result<Self, Self::Error> {
+        if value.q != T::one() {
+            return Err(TryFromRationalError(RationalErrorCause::NotInteger));
+        };
+        Ok(value.p.to_big_int())
+    }
+}
+
+impl From<TryFromBigIntError> for TryFromRationalError {
+    fn from(_: TryFromBigIntError) -> Self {
+        TryFromRationalError(RationalErrorCause::OutOfRange)
+    }
+}
+
+impl Display for TryFromRationalError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            RationalErrorCause::NotInteger => write!(f, "rational is not an integer"),
+            RationalErrorCause::OutOfRange => write!(f, "rational out of range of the target type"),
+        }
+    }
+}
+
+impl Error for TryFromRationalError {}
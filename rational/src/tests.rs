@@ -26,3 +26,21 @@ fn it_reduces() {
     assert_eq!(result.p.abs(), 5);
     assert_eq!(result.q.abs(), 2);
 }
+
+#[test]
+fn it_keeps_denominator_positive() {
+    let result = Rational::new(15, -6);
+    assert_eq!((result.p, result.q), (-5, 2));
+
+    let result = Rational::new(-15, -6);
+    assert_eq!((result.p, result.q), (5, 2));
+
+    let result = Rational::new(0, -6);
+    assert_eq!((result.p, result.q), (0, 1));
+
+    let result = Ratio::<i8>::new(i8::MIN, i8::MIN);
+    assert_eq!((result.p, result.q), (1, 1));
+
+    let result = Ratio::<i8>::new(2, i8::MIN);
+    assert_eq!((result.p, result.q), (-1, 64));
+}
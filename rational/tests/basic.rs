@@ -28,3 +28,52 @@ fn new_unchecked_works() {
 fn new_unchecked_does_not_work_when_denominator_is_zero() {
     let _res = Rational::new_unchecked(10, 0);
 }
+
+#[should_panic(expected = "Rational can't be represented with a positive denominator")]
+#[test]
+fn cant_make_denominator_positive() {
+    let _res = Rational::new(1, isize::MIN);
+}
+
+#[should_panic(expected = "Rational can't be represented with a positive denominator")]
+#[test]
+fn cant_make_odd_over_min_positive() {
+    let _res = Rational::new(isize::MAX, isize::MIN);
+}
+
+#[test]
+fn sign_belongs_to_numerator() {
+    let a = Rational::new(3, -4);
+    assert_eq!(a.numerator(), -3);
+    assert_eq!(a.denominator(), 4);
+
+    let a = -Rational::new(-3, -4);
+    assert_eq!(a.numerator(), -3);
+    assert_eq!(a.denominator(), 4);
+
+    let a = Rational::new(1, 2) / Rational::new(-3, 4);
+    assert_eq!(a.numerator(), -2);
+    assert_eq!(a.denominator(), 3);
+
+    let a = Rational::new(isize::MAX, 2) * Rational::new(-2, isize::MAX - 1);
+    assert_eq!(a.numerator(), -isize::MAX);
+    assert_eq!(a.denominator(), isize::MAX - 1);
+
+    let a = Rational::new(isize::MIN, isize::MAX) / Rational::new(isize::MIN, 3);
+    assert_eq!(a.numerator(), 3);
+    assert_eq!(a.denominator(), isize::MAX);
+}
+
+#[cfg(debug_assertions)]
+#[should_panic(expected = "Denominator must be positive")]
+#[test]
+fn new_unchecked_checks_sign_in_debug() {
+    let _res = Rational::new_unchecked(10, -3);
+}
+
+#[cfg(debug_assertions)]
+#[should_panic(expected = "Fraction must be reduced")]
+#[test]
+fn new_unchecked_checks_reduction_in_debug() {
+    let _res = Rational::new_unchecked(10, 4);
+}
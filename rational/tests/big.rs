@@ -0,0 +1,131 @@
+mod common;
+
+use common::xorshift;
+use rational::*;
+use std::collections::HashMap;
+
+#[test]
+fn big_int_matches_primitive_arithmetic() {
+    for (a, b) in sample_pairs() {
+        let (big_a, big_b) = (BigInt::from(a), BigInt::from(b));
+
+        assert_eq!(
+            big_a.clone() + big_b.clone(),
+            BigInt::from(a + b),
+            "{a} + {b}"
+        );
+        assert_eq!(
+            big_a.clone() - big_b.clone(),
+            BigInt::from(a - b),
+            "{a} - {b}"
+        );
+        assert_eq!(
+            big_a.clone() * big_b.clone(),
+            BigInt::from(a * b),
+            "{a} * {b}"
+        );
+        assert_eq!(
+            big_a.clone() / big_b.clone(),
+            BigInt::from(a / b),
+            "{a} / {b}"
+        );
+        assert_eq!(
+            big_a.clone() % big_b.clone(),
+            BigInt::from(a % b),
+            "{a} % {b}"
+        );
+        assert_eq!(big_a.cmp(&big_b), a.cmp(&b), "{a} cmp {b}");
+        assert_eq!(big_a.to_string(), a.to_string());
+    }
+}
+
+#[test]
+fn big_uint_divides_multi_limb_numbers() {
+    let divisor: BigUint = "340282366920938463463374607431768211457".parse().unwrap();
+    let quotient: BigUint = "98765432109876543210987654321098765432109876543210"
+        .parse()
+        .unwrap();
+    let remainder: BigUint = "340282366920938463463374607431768211456".parse().unwrap();
+
+    let dividend = quotient.clone() * divisor.clone() + remainder.clone();
+    assert_eq!(dividend.div_rem(&divisor), (quotient, remainder));
+}
+
+#[test]
+fn big_int_parses_and_prints() {
+    let text = "-1234567890123456789012345678901234567890";
+    let a: BigInt = text.parse().unwrap();
+    assert_eq!(a.to_string(), text);
+    assert!(a.is_negative());
+
+    assert!("".parse::<BigInt>().is_err());
+    assert!("12a".parse::<BigInt>().is_err());
+    assert_eq!("-0".parse::<BigInt>(), Ok(BigInt::from(0)));
+}
+
+#[test]
+fn big_int_converts_to_primitives() {
+    assert_eq!(i8::try_from(BigInt::from(-128)), Ok(i8::MIN));
+    assert_eq!(i128::try_from(BigInt::from(i128::MIN)), Ok(i128::MIN));
+    assert_eq!(u128::try_from(BigInt::from(u128::MAX)), Ok(u128::MAX));
+
+    assert!(i8::try_from(BigInt::from(128)).is_err());
+    assert!(u8::try_from(BigInt::from(-1)).is_err());
+    assert!(i128::try_from(BigInt::from(u128::MAX)).is_err());
+}
+
+#[test]
+fn big_rational_does_not_overflow() {
+    let mut a = BigRational::from(BigInt::from(1));
+    let factor: BigRational = "1.5".parse().unwrap();
+
+    for _ in 0..200 {
+        a *= &factor;
+    }
+    assert!(Ratio::<i128>::try_from(a.clone()).is_err());
+
+    for _ in 0..200 {
+        a /= &factor;
+    }
+    assert_eq!(Ratio::<i128>::try_from(a), Ok(Ratio::new(1, 1)));
+}
+
+#[test]
+fn big_rational_behaves_like_rational() {
+    let a: BigRational = "-3.(571428)".parse().unwrap();
+    let b = BigRational::from(Ratio::<i8>::new(1, -2));
+
+    assert_eq!(a.clone() + b.clone(), Ratio::<i64>::new(-57, 14).into());
+    assert_eq!(a.clone() - b.clone(), Ratio::<i64>::new(-43, 14).into());
+    assert_eq!(a.clone() * b.clone(), Ratio::<i64>::new(25, 14).into());
+    assert_eq!(a.clone() / b.clone(), Ratio::<i64>::new(50, 7).into());
+    assert!(a < b);
+
+    let mut hash_map = HashMap::new();
+    hash_map.insert(b.clone(), "negative half");
+    assert_eq!(hash_map[&Ratio::<i64>::new(-1, 2).into()], "negative half");
+}
+
+#[test]
+fn converts_between_rational_and_big_rational() {
+    let a: Ratio<i32> = Ratio::new(i32::MIN, 3);
+    let big = BigRational::from(a);
+    let back = Ratio::<i32>::try_from(big.clone()).unwrap();
+    assert_eq!(back.numerator(), a.numerator());
+    assert_eq!(back.denominator(), a.denominator());
+    assert!(Ratio::<i8>::try_from(big).is_err());
+}
+
+fn sample_pairs() -> Vec<(i128, i128)> {
+    let mut next = xorshift(0x2545F4914F6CDD1D);
+
+    let mut pairs = Vec::new();
+    for _ in 0..500 {
+        let a = next() as i64 as i128;
+        let b = (next() >> (next() % 60)) as i64 as i128;
+        if b != 0 {
+            pairs.push((a, b));
+        }
+    }
+    pairs
+}
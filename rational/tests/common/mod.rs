@@ -0,0 +1,22 @@
+//! Helpers shared by the integration tests.
+#![allow(dead_code)]
+
+use std::fmt::Debug;
+
+use rational::{ParseRationalError, RationalErrorKind};
+
+/// The kind and byte offset of a parse error.
+pub fn error_of<T: Debug>(result: Result<T, ParseRationalError>) -> (RationalErrorKind, usize) {
+    let error = result.unwrap_err();
+    (error.kind(), error.position())
+}
+
+/// A xorshift generator, deterministic so that failures can be reproduced.
+pub fn xorshift(mut state: u64) -> impl FnMut() -> u64 {
+    move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    }
+}
@@ -0,0 +1,96 @@
+mod common;
+
+use common::xorshift;
+use rational::*;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+#[test]
+fn it_compares_near_max() {
+    let a = Rational::new(isize::MAX, isize::MAX - 1);
+    let b = Rational::new(isize::MAX - 1, isize::MAX - 2);
+    assert!(a < b);
+    assert!(-a > -b);
+    assert_ne!(a, b);
+
+    let a = Rational::new(isize::MIN, isize::MAX);
+    let b = Rational::new(isize::MIN + 1, isize::MAX);
+    assert!(a < b);
+    assert_eq!(b, (-1).into());
+}
+
+#[test]
+fn it_finds_large_values_in_a_hashmap() {
+    let mut hash_map = HashMap::new();
+    hash_map.insert(Rational::new(isize::MIN, isize::MAX), "almost negative one");
+    hash_map.insert(Rational::new(isize::MIN, 3), "big negative");
+
+    assert_eq!(
+        hash_map[&Rational::new(isize::MIN, isize::MAX)],
+        "almost negative one"
+    );
+    assert_eq!(hash_map[&Rational::new(isize::MIN, 3)], "big negative");
+}
+
+#[test]
+fn it_compares_like_big_integer_reference() {
+    let values = sample_rationals::<i64>();
+    for a in &values {
+        for b in &values {
+            assert_eq!(a.cmp(b), reference_cmp(a, b), "{a:?} cmp {b:?}");
+            assert_eq!(a == b, reference_cmp(a, b) == Ordering::Equal);
+        }
+    }
+}
+
+#[test]
+fn it_compares_like_big_integer_reference_for_i8() {
+    let values = sample_rationals::<i8>();
+    for a in &values {
+        for b in &values {
+            assert_eq!(a.cmp(b), reference_cmp(a, b), "{a:?} cmp {b:?}");
+            assert_eq!(a == b, reference_cmp(a, b) == Ordering::Equal);
+        }
+    }
+}
+
+/// Compares by cross-multiplying the parts as arbitrary-precision integers, with the denominators made positive.
+fn reference_cmp<T: Integer>(a: &Ratio<T>, b: &Ratio<T>) -> Ordering {
+    fn parts<T: Integer>(value: &Ratio<T>) -> (BigInt, BigInt) {
+        let (p, q) = (
+            value.numerator().to_big_int(),
+            value.denominator().to_big_int(),
+        );
+        if q.is_negative() {
+            (-p, -q)
+        } else {
+            (p, q)
+        }
+    }
+
+    let (p1, q1) = parts(a);
+    let (p2, q2) = parts(b);
+    (p1 * q2).cmp(&(p2 * q1))
+}
+
+fn sample_rationals<T: Integer + TryFrom<i64>>() -> Vec<Ratio<T>> {
+    let mut next = xorshift(0x9E3779B97F4A7C15);
+
+    let mut values = Vec::new();
+    while values.len() < 200 {
+        // Mostly values near the bounds, where cross-multiplication overflows
+        let shift = if next().is_multiple_of(4) {
+            next() % 64
+        } else {
+            0
+        };
+        let p = T::try_from((next() as i64) >> shift);
+        let q = T::try_from((next() as i64) >> shift);
+        if let (Ok(p), Ok(q)) = (p, q) {
+            if q != T::zero() {
+                values.push(Ratio::new(p, q));
+            }
+        }
+    }
+    values
+}
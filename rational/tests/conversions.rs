@@ -1,3 +1,6 @@
+mod common;
+
+use common::error_of;
 use rational::*;
 
 #[test]
@@ -114,44 +117,44 @@ fn it_converts_from_string_negative() {
 #[test]
 fn it_doesnt_convert_from_string_negative_with_space() {
     let a: Result<Rational, _> = "- 1".parse();
-    assert_eq!(a, Err("Error parsing string"));
+    assert_eq!(error_of(a), (RationalErrorKind::InvalidChar, 1));
 
     let a: Result<Rational, _> = "- 1.5".parse();
-    assert_eq!(a, Err("Error parsing string"));
+    assert_eq!(error_of(a), (RationalErrorKind::InvalidChar, 1));
 }
 
 #[test]
 fn cant_parse_empty_string() {
     let result: Result<Rational, _> = "".parse();
-    assert_eq!(Err("Error parsing string"), result);
+    assert_eq!(error_of(result), (RationalErrorKind::Empty, 0));
 }
 
 #[test]
 fn cant_parse_incorrect_string_1() {
     let result: Result<Rational, _> = "sdjshdj".parse();
-    assert_eq!(Err("Error parsing string"), result);
+    assert_eq!(error_of(result), (RationalErrorKind::InvalidChar, 0));
 }
 
 #[test]
 fn cant_parse_incorrect_string_2() {
     let result: Result<Rational, _> = "1.2.3".parse();
-    assert_eq!(Err("Error parsing string"), result);
+    assert_eq!(error_of(result), (RationalErrorKind::InvalidChar, 3));
 }
 
 #[test]
 fn cant_parse_incorrect_string_3() {
     let result: Result<Rational, _> = "123-5.".parse();
-    assert_eq!(Err("Error parsing string"), result);
+    assert_eq!(error_of(result), (RationalErrorKind::InvalidChar, 3));
 }
 
 #[test]
 fn cant_parse_incorrect_string_4() {
     let result: Result<Rational, _> = "-".parse();
-    assert_eq!(Err("Error parsing string"), result);
+    assert_eq!(error_of(result), (RationalErrorKind::Empty, 0));
 }
 
 #[test]
 fn doesnt_parse_two_periods() {
     let res: Result<Rational, _> = "1..5".parse();
-    assert_eq!(res, Err("Error parsing string"));
+    assert_eq!(res.unwrap_err().kind(), RationalErrorKind::InvalidChar);
 }
@@ -0,0 +1,95 @@
+use rational::*;
+
+#[test]
+fn it_displays_fractions() {
+    assert_eq!(Rational::new(1, 2).to_string(), "1/2");
+    assert_eq!(Rational::new(10, -4).to_string(), "-5/2");
+    assert_eq!(Rational::new(6, 3).to_string(), "2");
+    assert_eq!(Rational::new(0, -3).to_string(), "0");
+    assert_eq!(
+        Rational::new(isize::MIN, 1).to_string(),
+        isize::MIN.to_string()
+    );
+}
+
+#[test]
+fn it_respects_width_and_sign_flags() {
+    let a = Rational::new(3, 4);
+    assert_eq!(format!("{a:>6}"), "   3/4");
+    assert_eq!(format!("{a:<6}|"), "3/4   |");
+    assert_eq!(format!("{a:+}"), "+3/4");
+    assert_eq!(format!("{:06.2}", -a), "-00.75");
+}
+
+#[test]
+fn it_displays_exact_decimals() {
+    assert_eq!(format!("{:#}", Rational::new(5, 1)), "5");
+    assert_eq!(format!("{:#}", Rational::new(5, 4)), "1.25");
+    assert_eq!(format!("{:#}", Rational::new(-1, 20)), "-0.05");
+    assert_eq!(format!("{:#}", Rational::new(1, 3)), "0.(3)");
+    assert_eq!(format!("{:#}", Rational::new(-25, 7)), "-3.(571428)");
+    assert_eq!(format!("{:#}", Rational::new(1, 6)), "0.1(6)");
+    assert_eq!(format!("{:#}", Rational::new(8, 195)), "0.0(410256)");
+    assert_eq!(
+        format!("{:#}", Rational::new(1, 7 * 64)),
+        "0.002232(142857)"
+    );
+}
+
+#[test]
+fn exact_decimals_parse_back() {
+    let values = [
+        Rational::new(1, 3),
+        Rational::new(-22, 7),
+        Rational::new(1, 13),
+        Rational::new(123456, 1000),
+        Rational::new(-1, 1024),
+        Rational::new(7, 12),
+    ];
+    for value in values {
+        let text = format!("{value:#}");
+        assert_eq!(text.parse::<Rational>(), Ok(value), "{text}");
+    }
+}
+
+#[test]
+fn it_displays_with_precision() {
+    assert_eq!(format!("{:.2}", Rational::new(1, 3)), "0.33");
+    assert_eq!(format!("{:.2}", Rational::new(2, 3)), "0.67");
+    assert_eq!(format!("{:.3}", Rational::new(-7, 6)), "-1.167");
+    assert_eq!(format!("{:.0}", Rational::new(7, 3)), "2");
+    assert_eq!(format!("{:.4}", Rational::new(1, 2)), "0.5000");
+    assert_eq!(format!("{:.2}", Rational::new(-1, 1000)), "-0.00");
+    assert_eq!(format!("{:.1}", Rational::new(99, 100)), "1.0");
+    assert_eq!(format!("{:.0}", Rational::new(-199, 2)), "-100");
+}
+
+#[test]
+fn it_rounds_ties_to_even() {
+    assert_eq!(format!("{:.0}", Rational::new(1, 2)), "0");
+    assert_eq!(format!("{:.0}", Rational::new(3, 2)), "2");
+    assert_eq!(format!("{:.0}", Rational::new(-5, 2)), "-2");
+    assert_eq!(format!("{:.2}", Rational::new(1, 8)), "0.12");
+    assert_eq!(format!("{:.2}", Rational::new(3, 8)), "0.38");
+}
+
+#[test]
+fn it_displays_values_near_bounds() {
+    let a = Rational::new(isize::MAX, isize::MAX - 1);
+    assert_eq!(format!("{a:.5}"), "1.00000");
+
+    let a: Ratio<i8> = Ratio::new(-128, 127);
+    assert_eq!(format!("{a:.3}"), "-1.008");
+    assert_eq!(
+        format!("{a:#}"),
+        "-1.(007874015748031496062992125984251968503937)"
+    );
+}
+
+#[test]
+fn big_rational_displays() {
+    let a: BigRational = "-123456789012345678901234567890.(3)".parse().unwrap();
+    assert_eq!(a.to_string(), "-370370367037037036703703703671/3");
+    assert_eq!(format!("{a:#}"), "-123456789012345678901234567890.(3)");
+    assert_eq!(format!("{a:.1}"), "-123456789012345678901234567890.3");
+}
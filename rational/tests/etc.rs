@@ -11,3 +11,89 @@ fn can_be_used_in_a_hashmap() {
     assert_eq!(hash_map[&Rational::new(1, 2)], "half");
     assert_eq!(hash_map[&Rational::new(1, -3)], "negative third");
 }
+
+#[test]
+fn it_reports_signs() {
+    let cases = [((-3, 4), -1), ((0, 1), 0), ((5, 2), 1)];
+    for ((p, q), sign) in cases {
+        let value = Rational::new(p, q);
+        assert_eq!(value.signum(), Rational::new(sign, 1), "{value}");
+        assert_eq!(value.is_zero(), sign == 0, "{value}");
+        assert_eq!(value.is_positive(), sign > 0, "{value}");
+        assert_eq!(value.is_negative(), sign < 0, "{value}");
+    }
+    assert_eq!(Ratio::<i8>::new(-128, 1).signum(), Ratio::new(-1, 1));
+    assert_eq!(
+        BigRational::new(BigInt::from(-7), BigInt::from(2)).signum(),
+        Ratio::new(BigInt::from(-1), BigInt::from(1))
+    );
+}
+
+#[test]
+fn it_takes_absolute_values() {
+    assert_eq!(Rational::new(-3, 4).abs(), Rational::new(3, 4));
+    assert_eq!(Rational::new(3, 4).abs(), Rational::new(3, 4));
+    assert_eq!(Rational::new(0, 1).abs(), Rational::new(0, 1));
+    assert_eq!(
+        Ratio::<i8>::new(-127, 2).checked_abs(),
+        Some(Ratio::new(127, 2))
+    );
+    assert_eq!(Ratio::<i8>::new(-128, 3).checked_abs(), None);
+}
+
+#[test]
+#[should_panic(expected = "attempt to negate with overflow")]
+fn it_panics_when_the_absolute_value_overflows() {
+    let _ = Ratio::<i8>::new(-128, 1).abs();
+}
+
+#[test]
+fn it_takes_reciprocals() {
+    assert_eq!(Rational::new(3, 4).recip(), Rational::new(4, 3));
+    assert_eq!(Rational::new(-3, 4).recip(), Rational::new(-4, 3));
+    assert_eq!(Rational::new(-1, 5).recip(), Rational::new(-5, 1));
+    assert_eq!(
+        Ratio::<i8>::new(-1, 127).checked_recip(),
+        Some(Ratio::new(-127, 1))
+    );
+    assert_eq!(Ratio::<i8>::new(-128, 7).checked_recip(), None);
+    assert_eq!(Ratio::<i8>::new(0, 1).checked_recip(), None);
+
+    let value = Rational::new(-7, 9);
+    assert_eq!(value.recip().recip(), value);
+    assert_eq!(value * value.recip(), Rational::new(1, 1));
+}
+
+#[test]
+#[should_panic(expected = "Can't divide by zero")]
+fn it_panics_on_the_reciprocal_of_zero() {
+    let _ = Rational::new(0, 1).recip();
+}
+
+#[test]
+fn it_detects_integers() {
+    assert!(Rational::new(0, 1).is_integer());
+    assert!(Rational::new(-8, 4).is_integer());
+    assert!(!Rational::new(-8, 3).is_integer());
+    assert!(Ratio::<i8>::new(-128, 1).is_integer());
+}
+
+#[test]
+fn it_finds_minimums_and_maximums() {
+    let (a, b) = (Rational::new(-1, 3), Rational::new(-1, 2));
+    assert_eq!(a.min(b), b);
+    assert_eq!(a.max(b), a);
+
+    let (low, high) = (Rational::new(-1, 4), Rational::new(1, 4));
+    assert_eq!(Rational::new(-1, 2).clamp(low, high), low);
+    assert_eq!(Rational::new(1, 8).clamp(low, high), Rational::new(1, 8));
+    assert_eq!(Rational::new(3, 2).clamp(low, high), high);
+
+    let values = [
+        Rational::new(2, 3),
+        Rational::new(-5, 7),
+        Rational::new(3, 4),
+    ];
+    assert_eq!(values.iter().min(), Some(&Rational::new(-5, 7)));
+    assert_eq!(values.iter().max(), Some(&Rational::new(3, 4)));
+}
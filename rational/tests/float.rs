@@ -0,0 +1,311 @@
+mod common;
+
+use common::xorshift;
+use rational::*;
+
+#[test]
+fn it_converts_floats_exactly() {
+    assert_eq!(Ratio::<i64>::try_from(0.5), Ok(Ratio::new(1, 2)));
+    assert_eq!(Ratio::<i64>::try_from(-2.75), Ok(Ratio::new(-11, 4)));
+    assert_eq!(Ratio::<i64>::try_from(3.0), Ok(Ratio::new(3, 1)));
+    assert_eq!(Ratio::<i64>::try_from(0.0), Ok(Ratio::new(0, 1)));
+    assert_eq!(Ratio::<i64>::try_from(-0.0), Ok(Ratio::new(0, 1)));
+    assert_eq!(
+        Ratio::<i64>::try_from(0.1),
+        Ok(Ratio::new(3602879701896397, 36028797018963968))
+    );
+    assert_eq!(
+        Ratio::<i32>::try_from(0.1f32),
+        Ok(Ratio::new(13421773, 134217728))
+    );
+    assert_eq!(Ratio::<i8>::try_from(-128.0f32), Ok(Ratio::new(-128, 1)));
+}
+
+#[test]
+fn it_converts_extreme_floats_into_big_rationals() {
+    let max = BigRational::try_from(f64::MAX).unwrap();
+    let expected = BigUint::from((1u64 << 53) - 1) << 971;
+    assert_eq!(max, Ratio::from(BigInt::from_unsigned(expected)));
+
+    let smallest = BigRational::try_from(f64::from_bits(1)).unwrap();
+    assert_eq!(smallest, Ratio::new(BigInt::from(1), power_of_two(1074)));
+
+    let min_normal = BigRational::try_from(-f64::MIN_POSITIVE).unwrap();
+    assert_eq!(min_normal, Ratio::new(BigInt::from(-1), power_of_two(1022)));
+}
+
+#[test]
+fn it_rejects_non_finite_floats() {
+    assert!(Ratio::<i64>::try_from(f64::NAN).is_err());
+    assert!(Ratio::<i64>::try_from(f64::INFINITY).is_err());
+    assert!(BigRational::try_from(f64::NEG_INFINITY).is_err());
+    assert!(BigRational::try_from(f32::NAN).is_err());
+    assert_eq!(
+        BigRational::try_from(f64::NAN).unwrap_err().to_string(),
+        "cannot convert a non-finite float"
+    );
+}
+
+#[test]
+fn it_rejects_floats_that_do_not_fit() {
+    assert_eq!(
+        Ratio::<i64>::try_from(-9223372036854775808.0),
+        Ok(Ratio::new(i64::MIN, 1))
+    );
+    let error = Ratio::<i64>::try_from(9223372036854775808f64).unwrap_err();
+    assert_eq!(error.to_string(), "float out of range of the target type");
+
+    assert!(Ratio::<i8>::try_from(128.0).is_err());
+    assert!(Ratio::<i8>::try_from(1.0 / 256.0).is_err());
+    assert_eq!(Ratio::<i16>::try_from(1.0 / 256.0), Ok(Ratio::new(1, 256)));
+    assert!(Ratio::<i64>::try_from(f64::MIN_POSITIVE).is_err());
+}
+
+#[test]
+fn it_round_trips_through_big_rationals() {
+    let mut next = xorshift(0x9E3779B97F4A7C15);
+    for _ in 0..2_000 {
+        let bits = next();
+        let value = f64::from_bits(bits);
+        if !value.is_finite() {
+            continue;
+        }
+        let exact = BigRational::try_from(value).unwrap();
+        let bits = exact.denominator().magnitude().bits();
+        assert_eq!(exact.denominator(), power_of_two(bits as u32 - 1));
+        if let Ok(narrow) = Ratio::<i64>::try_from(value) {
+            assert_eq!(BigRational::from(narrow), exact);
+        }
+    }
+}
+
+fn power_of_two(exponent: u32) -> BigInt {
+    BigInt::from_unsigned(BigUint::from(1u8) << exponent)
+}
+
+#[test]
+fn it_approximates_floats_with_bounded_denominators() {
+    assert_eq!(Ratio::<i64>::approximate(0.1, 100), Ok(Ratio::new(1, 10)));
+    assert_eq!(Ratio::<i64>::approximate(-0.1, 100), Ok(Ratio::new(-1, 10)));
+    assert_eq!(
+        Ratio::<i64>::approximate(1.0 / 3.0, 1_000_000),
+        Ok(Ratio::new(1, 3))
+    );
+    assert_eq!(Ratio::<i64>::approximate(0.0, 1), Ok(Ratio::new(0, 1)));
+    assert_eq!(Ratio::<i64>::approximate(2.5, 1), Ok(Ratio::new(2, 1)));
+    assert_eq!(Ratio::<i64>::approximate(0.75, 1), Ok(Ratio::new(1, 1)));
+
+    let pi = std::f64::consts::PI;
+    assert_eq!(Ratio::<i64>::approximate(pi, 1), Ok(Ratio::new(3, 1)));
+    assert_eq!(Ratio::<i64>::approximate(pi, 10), Ok(Ratio::new(22, 7)));
+    assert_eq!(Ratio::<i64>::approximate(pi, 100), Ok(Ratio::new(311, 99)));
+    assert_eq!(
+        Ratio::<i64>::approximate(pi, 1000),
+        Ok(Ratio::new(355, 113))
+    );
+
+    // The exact value of a tiny float doesn't fit, but its approximation does
+    assert_eq!(Ratio::<i8>::approximate(1e-300, 100), Ok(Ratio::new(0, 1)));
+    assert_eq!(Ratio::<i8>::approximate(0.01, 100), Ok(Ratio::new(1, 100)));
+    assert_eq!(Ratio::<i8>::approximate(0.501, 127), Ok(Ratio::new(1, 2)));
+
+    assert!(Ratio::<i64>::approximate(f64::NAN, 10).is_err());
+    assert!(Ratio::<i64>::approximate(1e20, 10).is_err());
+    assert!(BigRational::approximate(1e20, BigInt::from(10)).is_ok());
+}
+
+#[test]
+#[should_panic(expected = "max_denominator must be at least 1")]
+fn it_panics_on_non_positive_max_denominators() {
+    let _ = Ratio::<i64>::approximate(0.5, 0);
+}
+
+#[test]
+fn it_limits_denominators_like_python() {
+    // Values checked against Python's Fraction.limit_denominator
+    let value = Ratio::<i64>::new(3141592653589793, 1000000000000000);
+    assert_eq!(value.limit_denominator(1), Ratio::new(3, 1));
+    assert_eq!(value.limit_denominator(7), Ratio::new(22, 7));
+    assert_eq!(value.limit_denominator(106), Ratio::new(333, 106));
+    assert_eq!(value.limit_denominator(112), Ratio::new(333, 106));
+    assert_eq!(value.limit_denominator(113), Ratio::new(355, 113));
+    assert_eq!(value.limit_denominator(value.denominator()), value);
+
+    // 1/2 and 1/1 are equally close, ties go to the convergent
+    assert_eq!(
+        Ratio::<i64>::new(3, 4).limit_denominator(2),
+        Ratio::new(1, 1)
+    );
+    assert_eq!(
+        Ratio::<i64>::new(5, 8).limit_denominator(3),
+        Ratio::new(2, 3)
+    );
+    assert_eq!(
+        Ratio::<i64>::new(-5, 8).limit_denominator(3),
+        Ratio::new(-2, 3)
+    );
+
+    // The expansion starts from the floor, so negative ties don't mirror the positive ones
+    let ties = [
+        ((1, 2, 1), (0, 1)),
+        ((-1, 2, 1), (-1, 1)),
+        ((5, 2, 1), (2, 1)),
+        ((-5, 2, 1), (-3, 1)),
+        ((-3, 4, 2), (-1, 1)),
+        ((-1, 4, 2), (0, 1)),
+    ];
+    for ((p, q, max), (r, s)) in ties {
+        let value = Ratio::<i64>::new(p, q);
+        assert_eq!(value.limit_denominator(max), Ratio::new(r, s), "{value}");
+    }
+
+    let min = Ratio::<i8>::new(-128, 127);
+    assert_eq!(min.limit_denominator(1), Ratio::new(-1, 1));
+    assert_eq!(min.limit_denominator(127), min);
+
+    let big: BigRational = "3.14159265358979323846264338327950288".parse().unwrap();
+    assert_eq!(
+        big.limit_denominator(BigInt::from(1000)),
+        Ratio::new(BigInt::from(355), BigInt::from(113))
+    );
+}
+
+#[test]
+fn limited_denominators_are_the_closest_fractions() {
+    let mut next = xorshift(0x2545F4914F6CDD1D);
+    for _ in 0..300 {
+        let value = Ratio::<i64>::new(
+            (next() % 20_000) as i64 - 10_000,
+            (next() % 5_000 + 1) as i64,
+        );
+        let limit = (next() % 40 + 1) as i64;
+        let approximation = value.limit_denominator(limit);
+        assert!(approximation.denominator() <= limit);
+
+        let distance = |fraction: Ratio<i64>| {
+            let difference = fraction - value;
+            if difference < Ratio::new(0, 1) {
+                -difference
+            } else {
+                difference
+            }
+        };
+        for q in 1..=limit {
+            let p = (value * Ratio::from(q)).numerator() / (value * Ratio::from(q)).denominator();
+            for p in [p - 1, p, p + 1] {
+                assert!(
+                    distance(approximation) <= distance(Ratio::new(p, q)),
+                    "{value} {limit}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn it_converts_into_the_nearest_float() {
+    assert_eq!(Ratio::<i64>::new(0, 1).to_f64(), 0.0);
+    assert_eq!(Ratio::<i64>::new(1, 10).to_f64(), 0.1);
+    assert_eq!(Ratio::<i64>::new(-2, 3).to_f64(), -2.0 / 3.0);
+    assert_eq!(Ratio::<i64>::new(1, 10).to_f32(), 0.1f32);
+    assert_eq!(Ratio::<i8>::new(-128, 127).to_f32(), -128.0 / 127.0);
+    assert_eq!(Ratio::new(i128::MAX, 1).to_f64(), 2f64.powi(127));
+    assert_eq!(Ratio::new(i64::MIN, i64::MAX).to_f64(), -1.0);
+}
+
+#[test]
+fn it_rounds_ties_to_even() {
+    let exact = |numerator: i64| Ratio::new(numerator, 1).to_f64();
+    assert_eq!(exact((1 << 53) + 1), 9007199254740992.0);
+    assert_eq!(exact((1 << 53) + 2), 9007199254740994.0);
+    assert_eq!(exact((1 << 53) + 3), 9007199254740996.0);
+    assert_eq!(exact(-(1 << 53) - 3), -9007199254740996.0);
+
+    // Anything above the tie rounds up
+    let above = Ratio::<i128>::new((1 << 54) + 3, 2).to_f64();
+    assert_eq!(above, 9007199254740994.0);
+
+    let exact = |numerator: i64| Ratio::new(numerator, 1).to_f32();
+    assert_eq!(exact((1 << 24) + 1), 16777216.0);
+    assert_eq!(exact((1 << 24) + 3), 16777220.0);
+}
+
+#[test]
+fn it_does_not_round_twice() {
+    // Rounding to f64 first gives 1 + 2^-24, which is a tie for f32 and rounds down to 1
+    let value = Ratio::<i64>::new((1 << 53) + (1 << 29) + 1, 1 << 53);
+    assert_eq!(value.to_f64() as f32, 1.0);
+    assert_eq!(value.to_f32(), 1.0 + f32::EPSILON);
+}
+
+#[test]
+fn it_converts_extreme_values() {
+    let max = BigRational::try_from(f64::MAX).unwrap();
+    assert_eq!(max.to_f64(), f64::MAX);
+    assert_eq!((-max.clone()).to_f64(), -f64::MAX);
+
+    // Half an ulp above the maximum is a tie, and the maximum has an odd mantissa
+    let half_ulp = Ratio::from(power_of_two(970));
+    assert_eq!((max.clone() + half_ulp.clone()).to_f64(), f64::INFINITY);
+    let below = half_ulp.clone() - Ratio::new(BigInt::from(1), BigInt::from(2));
+    assert_eq!((max.clone() + below).to_f64(), f64::MAX);
+    assert_eq!(max.to_f32(), f32::INFINITY);
+    assert_eq!((-max).to_f32(), f32::NEG_INFINITY);
+
+    let smallest = BigRational::try_from(f64::from_bits(1)).unwrap();
+    assert_eq!(smallest.to_f64(), f64::from_bits(1));
+    let half = smallest.clone() / BigRational::from(BigInt::from(2));
+    assert_eq!(half.to_f64(), 0.0);
+    let three_halves = half.clone() * BigRational::from(BigInt::from(3));
+    assert_eq!(three_halves.to_f64(), f64::from_bits(2));
+    let quarter = half.clone() / BigRational::from(BigInt::from(2));
+    assert_eq!((half.clone() + quarter).to_f64(), f64::from_bits(1));
+    assert_eq!((-half).to_f64().to_bits(), (-0.0f64).to_bits());
+
+    let min_normal = BigRational::try_from(f64::MIN_POSITIVE).unwrap();
+    assert_eq!(min_normal.to_f64(), f64::MIN_POSITIVE);
+    let largest_subnormal = BigRational::try_from(f64::MIN_POSITIVE - f64::from_bits(1)).unwrap();
+    assert_eq!(
+        ((min_normal + largest_subnormal) / BigRational::from(BigInt::from(2))).to_f64(),
+        f64::MIN_POSITIVE
+    );
+
+    assert_eq!(Ratio::<i64>::new(1, 1 << 62).to_f32(), 2f32.powi(-62));
+    let tiny: BigRational = "1e-46".parse().unwrap();
+    assert_eq!(tiny.to_f32(), 0.0);
+    assert_eq!(tiny.to_f64(), 1e-46);
+    let subnormal: BigRational = "1e-40".parse().unwrap();
+    assert_eq!(subnormal.to_f32(), 1e-40f32);
+}
+
+#[test]
+fn floats_round_trip_through_exact_conversion() {
+    let mut next = xorshift(0x9E3779B97F4A7C15);
+    for _ in 0..5_000 {
+        let bits = next();
+        let double = f64::from_bits(bits);
+        if double.is_finite() {
+            assert_eq!(BigRational::try_from(double).unwrap().to_f64(), double);
+        }
+        let single = f32::from_bits(bits as u32);
+        if single.is_finite() {
+            assert_eq!(BigRational::try_from(single).unwrap().to_f32(), single);
+        }
+    }
+}
+
+#[test]
+fn it_agrees_with_float_parsing() {
+    let mut next = xorshift(0x2545F4914F6CDD1D);
+    for _ in 0..3_000 {
+        let digits: String = (0..next() % 30 + 1)
+            .map(|_| char::from(b'0' + (next() % 10) as u8))
+            .collect();
+        let exponent = (next() % 700) as i64 - 350;
+        let text = format!("{digits}e{exponent}");
+
+        let value: BigRational = text.parse().unwrap();
+        assert_eq!(value.to_f64(), text.parse::<f64>().unwrap(), "{text}");
+        assert_eq!(value.to_f32(), text.parse::<f32>().unwrap(), "{text}");
+    }
+}
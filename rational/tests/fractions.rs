@@ -0,0 +1,129 @@
+mod common;
+
+use common::error_of;
+use rational::*;
+
+#[test]
+fn it_parses_fractions() {
+    assert_eq!("3/4".parse(), Ok(Rational::new(3, 4)));
+    assert_eq!("-3/4".parse(), Ok(Rational::new(-3, 4)));
+    assert_eq!("6/8".parse(), Ok(Rational::new(3, 4)));
+    assert_eq!("0/5".parse(), Ok(Rational::new(0, 1)));
+    assert_eq!("7/1".parse(), Ok(Rational::new(7, 1)));
+    assert_eq!("007/014".parse(), Ok(Rational::new(1, 2)));
+}
+
+#[test]
+fn it_parses_signed_denominators() {
+    assert_eq!("3/-4".parse(), Ok(Rational::new(-3, 4)));
+    assert_eq!("-3/-4".parse(), Ok(Rational::new(3, 4)));
+    assert_eq!("-0/-4".parse(), Ok(Rational::new(0, 1)));
+}
+
+#[test]
+fn it_parses_mixed_numbers() {
+    assert_eq!("1 1/2".parse(), Ok(Rational::new(3, 2)));
+    assert_eq!("-2 1/3".parse(), Ok(Rational::new(-7, 3)));
+    assert_eq!("0 3/4".parse(), Ok(Rational::new(3, 4)));
+    assert_eq!("2   4/8".parse(), Ok(Rational::new(5, 2)));
+    assert_eq!("2\t5/4".parse(), Ok(Rational::new(13, 4)));
+}
+
+#[test]
+fn fractions_parse_back() {
+    let values = [
+        Rational::new(1, 3),
+        Rational::new(-22, 7),
+        Rational::new(5, 1),
+        Rational::new(isize::MIN, 1),
+        Rational::new(isize::MAX, isize::MIN + 1),
+    ];
+    for value in values {
+        assert_eq!(value.to_string().parse(), Ok(value), "{value}");
+    }
+}
+
+#[test]
+fn it_rejects_zero_denominators() {
+    assert_eq!(
+        error_of("1/0".parse::<Rational>()),
+        (RationalErrorKind::ZeroDenominator, 2)
+    );
+    assert_eq!(
+        error_of("-5/-000".parse::<Rational>()),
+        (RationalErrorKind::ZeroDenominator, 3)
+    );
+    assert_eq!(
+        error_of("1 1/0".parse::<Rational>()),
+        (RationalErrorKind::ZeroDenominator, 4)
+    );
+}
+
+#[test]
+fn it_reports_fraction_overflow() {
+    let result: Result<Ratio<i8>, _> = "128/1".parse();
+    assert_eq!(error_of(result), (RationalErrorKind::Overflow, 0));
+
+    let result: Result<Ratio<i8>, _> = "1/-128".parse();
+    assert_eq!(error_of(result), (RationalErrorKind::Overflow, 0));
+
+    let result: Result<Ratio<i8>, _> = "-128/-2".parse();
+    assert_eq!(result, Ok(Ratio::new(64, 1)));
+
+    let result: Result<Ratio<i8>, _> = "-127 1/1".parse();
+    assert_eq!(result, Ok(Ratio::new(-128, 1)));
+
+    let result: Result<Ratio<i8>, _> = "-127 2/1".parse();
+    assert_eq!(error_of(result), (RationalErrorKind::Overflow, 0));
+
+    let result: Result<Ratio<i8>, _> = "1000/2000".parse();
+    assert_eq!(result, Ok(Ratio::new(1, 2)));
+}
+
+#[test]
+fn it_rejects_malformed_fractions() {
+    assert_eq!(
+        error_of("1/".parse::<Rational>()),
+        (RationalErrorKind::Empty, 2)
+    );
+    assert_eq!(
+        error_of("/2".parse::<Rational>()),
+        (RationalErrorKind::InvalidChar, 0)
+    );
+    assert_eq!(
+        error_of("1/2/3".parse::<Rational>()),
+        (RationalErrorKind::InvalidChar, 3)
+    );
+    assert_eq!(
+        error_of("1.5/2".parse::<Rational>()),
+        (RationalErrorKind::InvalidChar, 3)
+    );
+    assert_eq!(
+        error_of("1/2.5".parse::<Rational>()),
+        (RationalErrorKind::InvalidChar, 3)
+    );
+    assert_eq!(
+        error_of("1 / 2".parse::<Rational>()),
+        (RationalErrorKind::InvalidChar, 1)
+    );
+    assert_eq!(
+        error_of("1 1".parse::<Rational>()),
+        (RationalErrorKind::InvalidChar, 3)
+    );
+    assert_eq!(
+        error_of("1 ".parse::<Rational>()),
+        (RationalErrorKind::InvalidChar, 1)
+    );
+    assert_eq!(
+        error_of("1 -1/2".parse::<Rational>()),
+        (RationalErrorKind::InvalidChar, 1)
+    );
+    assert_eq!(
+        error_of("1 1/-2".parse::<Rational>()),
+        (RationalErrorKind::InvalidChar, 4)
+    );
+    assert_eq!(
+        error_of(" 1/2".parse::<Rational>()),
+        (RationalErrorKind::InvalidChar, 0)
+    );
+}
@@ -0,0 +1,166 @@
+mod common;
+
+use common::xorshift;
+use rational::*;
+
+#[test]
+fn it_reports_overflow_instead_of_panicking() {
+    let result: Result<Rational, _> = "0.(12345678901234567891)".parse();
+    assert_eq!(result.unwrap_err().kind(), RationalErrorKind::Overflow);
+
+    // Only the reduced value has to fit
+    let result: Result<Rational, _> = "0.(12345678901234567890)".parse();
+    assert_eq!(result, Ok(Rational::new(137174210, 1111111111)));
+
+    let result: Result<Ratio<i8>, _> = "128".parse();
+    assert_eq!(result.unwrap_err().kind(), RationalErrorKind::Overflow);
+    assert_eq!("-128".parse(), Ok(Ratio::<i8>::from(i8::MIN)));
+    assert_eq!("-256/2".parse(), Ok(Ratio::<i8>::from(i8::MIN)));
+    let result: Result<Ratio<i8>, _> = "-1/128".parse();
+    assert_eq!(result.unwrap_err().kind(), RationalErrorKind::Overflow);
+
+    let result: Result<Ratio<i8>, _> = "12.(001)".parse();
+    let error = result.unwrap_err();
+    assert_eq!(
+        (error.kind(), error.position()),
+        (RationalErrorKind::Overflow, 0)
+    );
+
+    let result: Result<Ratio<i64>, _> = "99999999999999999999999999999.9".parse();
+    assert_eq!(result.unwrap_err().kind(), RationalErrorKind::Overflow);
+}
+
+#[test]
+fn it_parses_long_strings_with_representable_values() {
+    assert_eq!("-128".parse(), Ok(Ratio::<i8>::new(-128, 1)));
+    assert_eq!("0.(01)".parse(), Ok(Ratio::<i8>::new(1, 99)));
+    assert_eq!(
+        "-1.(007874015748031496062992125984251968503937)".parse(),
+        Ok(Ratio::<i8>::new(-128, 127))
+    );
+    assert_eq!(
+        "0.50000000000000000000000000000000000000000000".parse(),
+        Ok(Ratio::<i8>::new(1, 2))
+    );
+}
+
+#[test]
+fn parse_never_panics_on_arbitrary_input() {
+    let mut next = xorshift(0x9E3779B97F4A7C15);
+    let alphabet: Vec<char> = "0123456789..--(())[]9999000 e/_,+\u{2212}\u{305}\u{305}…x€"
+        .chars()
+        .collect();
+
+    for _ in 0..20_000 {
+        let length = (next() % 40) as usize;
+        let text: String = (0..length)
+            .map(|_| alphabet[(next() % alphabet.len() as u64) as usize])
+            .collect();
+        check_parse(&text);
+    }
+}
+
+#[test]
+fn parse_never_panics_on_mutated_numbers() {
+    let mut next = xorshift(0x2545F4914F6CDD1D);
+    let seeds = [
+        "-3.(571428)",
+        "0.0(410256)",
+        "170141183460469231731687303715884105727",
+        "-0.(000000000000000000000000000000000000001)",
+        "99999999999999999999.(9)",
+        "-.5",
+    ];
+
+    for _ in 0..20_000 {
+        let mut text: Vec<char> = seeds[(next() % seeds.len() as u64) as usize]
+            .chars()
+            .collect();
+        for _ in 0..next() % 4 + 1 {
+            let index = (next() % (text.len() as u64 + 1)) as usize;
+            let char = ['9', '0', '(', ')', '.', '-', '\u{305}', '€'][(next() % 8) as usize];
+            match next() % 3 {
+                0 if index < text.len() => text[index] = char,
+                1 if index < text.len() => {
+                    text.remove(index);
+                }
+                _ => text.insert(index, char),
+            }
+        }
+        check_parse(&text.into_iter().collect::<String>());
+    }
+}
+
+#[test]
+fn parse_agrees_with_big_numbers_near_the_bounds() {
+    let mut next = xorshift(0x9E3779B97F4A7C15);
+    let digits = |next: &mut dyn FnMut() -> u64, count: u64| -> String {
+        (0..next() % count)
+            .map(|_| char::from(b'0' + (next() % 10) as u8))
+            .collect()
+    };
+
+    for _ in 0..20_000 {
+        let sign = ["", "-"][(next() % 2) as usize];
+        let text = match next() % 3 {
+            0 => format!(
+                "{sign}{}.{}({}){}",
+                digits(&mut next, 6),
+                digits(&mut next, 4),
+                digits(&mut next, 4) + "1",
+                ["", "e2", "e-3", "e40"][(next() % 4) as usize]
+            ),
+            1 => format!(
+                "{sign}{}/{}",
+                digits(&mut next, 6) + "1",
+                digits(&mut next, 6) + "0"
+            ),
+            _ => format!(
+                "{sign}{} {}/{}",
+                digits(&mut next, 4) + "1",
+                digits(&mut next, 4) + "2",
+                digits(&mut next, 4) + "3"
+            ),
+        };
+        check_parse(&text);
+    }
+}
+
+/// Parses the text into every integer type, checking errors point into the string
+/// and successfully parsed values agree with the arbitrary-precision result.
+fn check_parse(text: &str) {
+    let exact = text.parse::<BigRational>();
+    check_width::<i8>(text, &exact);
+    check_width::<i16>(text, &exact);
+    check_width::<i32>(text, &exact);
+    check_width::<i64>(text, &exact);
+    check_width::<i128>(text, &exact);
+    check_width::<isize>(text, &exact);
+
+    let lenient = Ratio::<BigInt>::parse_with(text, &ParseOptions::lenient());
+    if exact.is_ok() {
+        assert_eq!(lenient, exact, "{text}");
+    }
+    if let Err(error) = Ratio::<i8>::parse_with(text, &ParseOptions::lenient()) {
+        assert!(text.is_char_boundary(error.position()), "{text}");
+    }
+}
+
+fn check_width<T>(text: &str, exact: &Result<BigRational, ParseRationalError>)
+where
+    T: Integer,
+    BigRational: From<Ratio<T>>,
+{
+    match (text.parse::<Ratio<T>>(), exact) {
+        (Ok(value), Ok(exact)) => assert_eq!(&BigRational::from(value), exact, "{text}"),
+        (Err(error), Ok(_)) => {
+            assert_eq!(error.kind(), RationalErrorKind::Overflow, "{text}")
+        }
+        (Err(error), Err(exact_error)) => assert_eq!(&error, exact_error, "{text}"),
+        (Ok(_), Err(_)) => panic!("{text} only parses with a fixed width"),
+    }
+    if let Err(error) = text.parse::<Ratio<T>>() {
+        assert!(error.position() <= text.len(), "{text}");
+        assert!(text.is_char_boundary(error.position()), "{text}");
+    }
+}
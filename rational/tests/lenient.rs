@@ -0,0 +1,196 @@
+mod common;
+
+use common::error_of;
+use rational::*;
+
+#[test]
+fn it_allows_plus_signs() {
+    let options = ParseOptions::new().plus_sign(true);
+    assert_eq!(
+        Rational::parse_with("+1.5", &options),
+        Ok(Rational::new(3, 2))
+    );
+    assert_eq!(
+        Rational::parse_with("+3/+4", &options),
+        Ok(Rational::new(3, 4))
+    );
+    assert_eq!(
+        Rational::parse_with("+1 1/2", &options),
+        Ok(Rational::new(3, 2))
+    );
+    assert_eq!(
+        Rational::parse_with("+2e+1", &options),
+        Ok(Rational::new(20, 1))
+    );
+    assert_eq!(
+        error_of(Ratio::<isize>::parse_with("+-1", &options)),
+        (RationalErrorKind::InvalidChar, 1)
+    );
+    assert_eq!(
+        error_of(Ratio::<isize>::parse_with("+1.5", &ParseOptions::new())),
+        (RationalErrorKind::InvalidChar, 0)
+    );
+}
+
+#[test]
+fn it_allows_separators_between_digits() {
+    let options = ParseOptions::new().underscores(true);
+    assert_eq!(
+        Rational::parse_with("1_000.25", &options),
+        Ok(Rational::new(4001, 4))
+    );
+    assert_eq!(
+        Rational::parse_with("0.000_001", &options),
+        Ok(Rational::new(1, 1_000_000))
+    );
+    assert_eq!(
+        Rational::parse_with("0.(1_2)", &options),
+        Ok(Rational::new(4, 33))
+    );
+    assert_eq!(
+        Rational::parse_with("1_0/2_0", &options),
+        Ok(Rational::new(1, 2))
+    );
+    assert_eq!(
+        Rational::parse_with("1e1_0", &options),
+        Ok(Rational::new(10_000_000_000isize, 1))
+    );
+    assert_eq!(
+        error_of(Ratio::<isize>::parse_with("1,000", &options)),
+        (RationalErrorKind::InvalidChar, 1)
+    );
+
+    let options = ParseOptions::new().commas(true);
+    assert_eq!(
+        Rational::parse_with("1,234.5", &options),
+        Ok(Rational::new(2469, 2))
+    );
+    assert_eq!(
+        Rational::parse_with("-12,345,678", &options),
+        Ok(Rational::new(-12_345_678, 1))
+    );
+}
+
+#[test]
+fn it_rejects_misplaced_separators() {
+    let options = ParseOptions::lenient();
+    for (text, position) in [
+        ("_1", 0),
+        ("1_", 1),
+        ("1__0", 1),
+        ("1_.5", 1),
+        ("1._5", 2),
+        (",5", 0),
+        ("1,_0", 1),
+    ] {
+        assert_eq!(
+            error_of(Ratio::<isize>::parse_with(text, &options)),
+            (RationalErrorKind::InvalidChar, position),
+            "{text}"
+        );
+    }
+}
+
+#[test]
+fn it_allows_surrounding_whitespace() {
+    let options = ParseOptions::new().whitespace(true);
+    assert_eq!(
+        Rational::parse_with("  1.5\t", &options),
+        Ok(Rational::new(3, 2))
+    );
+    assert_eq!(
+        Rational::parse_with("\n3/4 ", &options),
+        Ok(Rational::new(3, 4))
+    );
+    assert_eq!(
+        Rational::parse_with(" 7 ", &options),
+        Ok(Rational::new(7, 1))
+    );
+    assert_eq!(
+        Rational::parse_with(" 1 1/2 ", &options),
+        Ok(Rational::new(3, 2))
+    );
+    assert_eq!(
+        error_of(Ratio::<isize>::parse_with("   ", &options)),
+        (RationalErrorKind::Empty, 3)
+    );
+    assert_eq!(
+        error_of(Ratio::<isize>::parse_with("1 x", &options)),
+        (RationalErrorKind::InvalidChar, 1)
+    );
+    assert_eq!(
+        error_of(Ratio::<isize>::parse_with("- 1", &options)),
+        (RationalErrorKind::InvalidChar, 1)
+    );
+    assert_eq!(
+        error_of(Ratio::<isize>::parse_with(" 1", &ParseOptions::new())),
+        (RationalErrorKind::InvalidChar, 0)
+    );
+}
+
+#[test]
+fn it_allows_unicode_minus() {
+    let options = ParseOptions::new().unicode_minus(true);
+    assert_eq!(
+        Rational::parse_with("\u{2212}0.(3)", &options),
+        Ok(Rational::new(-1, 3))
+    );
+    assert_eq!(
+        Rational::parse_with("1/\u{2212}2", &options),
+        Ok(Rational::new(-1, 2))
+    );
+    assert_eq!(
+        Rational::parse_with("1e\u{2212}2", &options),
+        Ok(Rational::new(1, 100))
+    );
+    assert_eq!(
+        error_of(Ratio::<isize>::parse_with("\u{2212}\u{2212}1", &options)),
+        (RationalErrorKind::InvalidChar, 3)
+    );
+    assert_eq!(
+        error_of(Ratio::<isize>::parse_with(
+            "\u{2212}1",
+            &ParseOptions::new()
+        )),
+        (RationalErrorKind::InvalidChar, 0)
+    );
+}
+
+#[test]
+fn strict_options_match_from_str() {
+    let texts = [
+        "1.5",
+        "-0.(3)",
+        "3/-4",
+        "-2 1/3",
+        "1e5",
+        "+1",
+        "1_0",
+        "1,0",
+        " 1",
+        "\u{2212}1",
+        "",
+    ];
+    for text in texts {
+        assert_eq!(
+            Ratio::<isize>::parse_with(text, &ParseOptions::new()),
+            text.parse(),
+            "{text}"
+        );
+    }
+}
+
+#[test]
+fn it_combines_leniencies() {
+    let options = ParseOptions::lenient();
+    assert_eq!(
+        Rational::parse_with(" \u{2212}1,234_567.5 ", &options),
+        Ok(Rational::new(-2_469_135, 2))
+    );
+    assert_eq!(
+        Ratio::<i8>::parse_with(" +1,000 ", &options)
+            .unwrap_err()
+            .kind(),
+        RationalErrorKind::Overflow
+    );
+}
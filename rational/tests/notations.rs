@@ -0,0 +1,177 @@
+mod common;
+
+use common::error_of;
+use rational::*;
+
+#[test]
+fn it_parses_percentages() {
+    assert_eq!(Rational::parse_percent("12.5%"), Ok(Rational::new(1, 8)));
+    assert_eq!(Rational::parse_percent("100%"), Ok(Rational::new(1, 1)));
+    assert_eq!(
+        Rational::parse_percent("-0.(3)%"),
+        Ok(Rational::new(-1, 300))
+    );
+    assert_eq!(Rational::parse_percent("1 1/2%"), Ok(Rational::new(3, 200)));
+    assert_eq!(Rational::parse_percent("2e3%"), Ok(Rational::new(20, 1)));
+    assert_eq!(
+        error_of(Ratio::<isize>::parse_percent("12.5")),
+        (RationalErrorKind::MissingSymbol, 4)
+    );
+    assert_eq!(
+        error_of(Ratio::<isize>::parse_percent("12.5 %")),
+        (RationalErrorKind::InvalidChar, 4)
+    );
+    assert_eq!(
+        error_of(Ratio::<isize>::parse_percent("12.5%%")),
+        (RationalErrorKind::InvalidChar, 5)
+    );
+    assert_eq!(
+        error_of(Ratio::<isize>::parse_percent("%")),
+        (RationalErrorKind::InvalidChar, 0)
+    );
+}
+
+#[test]
+fn it_parses_per_mille() {
+    assert_eq!(Rational::parse_per_mille("7‰"), Ok(Rational::new(7, 1000)));
+    assert_eq!(
+        Rational::parse_per_mille("-2.5‰"),
+        Ok(Rational::new(-1, 400))
+    );
+    assert_eq!(
+        error_of(Ratio::<isize>::parse_per_mille("7%")),
+        (RationalErrorKind::InvalidChar, 1)
+    );
+}
+
+#[test]
+fn scaled_values_only_have_to_fit_after_scaling() {
+    assert_eq!(Ratio::<i8>::parse_percent("12700%"), Ok(Ratio::new(127, 1)));
+    assert_eq!(
+        Ratio::<i8>::parse_percent("1.27e4%"),
+        Ok(Ratio::new(127, 1))
+    );
+    assert_eq!(
+        Ratio::<i8>::parse_per_mille("-128000‰"),
+        Ok(Ratio::new(-128, 1))
+    );
+    assert_eq!(
+        Ratio::<i8>::parse_percent("12800%").unwrap_err().kind(),
+        RationalErrorKind::Overflow
+    );
+}
+
+#[test]
+fn it_parses_ratios() {
+    assert_eq!(Rational::parse_ratio("16:9"), Ok(Rational::new(16, 9)));
+    assert_eq!(Rational::parse_ratio("4:2"), Ok(Rational::new(2, 1)));
+    assert_eq!(Rational::parse_ratio("-1:3"), Ok(Rational::new(-1, 3)));
+    assert_eq!(Rational::parse_ratio("2.5:0.5"), Ok(Rational::new(5, 1)));
+    assert_eq!(Rational::parse_ratio("1/2:3/4"), Ok(Rational::new(2, 3)));
+    assert_eq!(Rational::parse_ratio("0:5"), Ok(Rational::new(0, 1)));
+    assert_eq!(Ratio::<i8>::parse_ratio("1000:2000"), Ok(Ratio::new(1, 2)));
+    assert_eq!(
+        Ratio::<i64>::parse_ratio("1e30:3e29"),
+        Ok(Ratio::new(10, 3))
+    );
+}
+
+#[test]
+fn it_rejects_malformed_ratios() {
+    assert_eq!(
+        error_of(Ratio::<isize>::parse_ratio("16:0")),
+        (RationalErrorKind::ZeroDenominator, 3)
+    );
+    assert_eq!(
+        error_of(Ratio::<isize>::parse_ratio("16")),
+        (RationalErrorKind::MissingSymbol, 2)
+    );
+    assert_eq!(
+        error_of(Ratio::<isize>::parse_ratio("16:")),
+        (RationalErrorKind::Empty, 3)
+    );
+    assert_eq!(
+        error_of(Ratio::<isize>::parse_ratio("16:9:1")),
+        (RationalErrorKind::InvalidChar, 4)
+    );
+    assert_eq!(
+        error_of(Ratio::<isize>::parse_ratio("16 : 9")),
+        (RationalErrorKind::InvalidChar, 2)
+    );
+    assert_eq!(
+        Ratio::<i8>::parse_ratio("200:1").unwrap_err().kind(),
+        RationalErrorKind::Overflow
+    );
+}
+
+#[test]
+fn it_displays_percentages() {
+    assert_eq!(Rational::new(1, 8).display_percent().to_string(), "12.5%");
+    assert_eq!(Rational::new(1, 3).display_percent().to_string(), "33.(3)%");
+    assert_eq!(Rational::new(-3, 2).display_percent().to_string(), "-150%");
+    assert_eq!(
+        format!("{:.2}", Rational::new(2, 3).display_percent()),
+        "66.67%"
+    );
+    assert_eq!(
+        format!("{:>8.1}", Rational::new(1, 8).display_percent()),
+        "   12.5%"
+    );
+    assert_eq!(
+        format!("{:+}", Rational::new(1, 4).display_percent()),
+        "+25%"
+    );
+    assert_eq!(
+        Ratio::<i8>::new(127, 1).display_percent().to_string(),
+        "12700%"
+    );
+}
+
+#[test]
+fn it_displays_per_mille() {
+    assert_eq!(Rational::new(7, 1000).display_per_mille().to_string(), "7‰");
+    assert_eq!(
+        Rational::new(-1, 3).display_per_mille().to_string(),
+        "-333.(3)‰"
+    );
+    assert_eq!(
+        format!("{:.0}", Rational::new(1, 3).display_per_mille()),
+        "333‰"
+    );
+}
+
+#[test]
+fn it_displays_ratios() {
+    assert_eq!(Rational::new(32, 18).display_ratio().to_string(), "16:9");
+    assert_eq!(Rational::new(-1, 3).display_ratio().to_string(), "-1:3");
+    assert_eq!(Rational::new(2, 1).display_ratio().to_string(), "2:1");
+    assert_eq!(
+        format!("{:>6}", Rational::new(4, 3).display_ratio()),
+        "   4:3"
+    );
+}
+
+#[test]
+fn notations_round_trip() {
+    let values = [
+        Rational::new(1, 8),
+        Rational::new(-1, 3),
+        Rational::new(22, 7),
+        Rational::new(0, 1),
+        Rational::new(isize::MAX, 1),
+    ];
+    for value in values {
+        let percent = value.display_percent().to_string();
+        assert_eq!(Rational::parse_percent(&percent), Ok(value), "{percent}");
+
+        let per_mille = value.display_per_mille().to_string();
+        assert_eq!(
+            Rational::parse_per_mille(&per_mille),
+            Ok(value),
+            "{per_mille}"
+        );
+
+        let ratio = value.display_ratio().to_string();
+        assert_eq!(Rational::parse_ratio(&ratio), Ok(value), "{ratio}");
+    }
+}
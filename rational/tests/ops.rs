@@ -255,6 +255,377 @@ fn it_compares_equals() {
     assert!(b <= a);
 }
 
+#[test]
+fn it_multiplies_near_max() {
+    let a = Rational::new(isize::MAX, 3);
+    let b = Rational::new(3, isize::MAX);
+    check_multiplication(a, b, 1.into());
+
+    let a = Rational::new(isize::MAX, 2);
+    let b = Rational::new(-2, isize::MAX - 1);
+    let res = a * b;
+    assert_eq!(res.numerator() * res.denominator().signum(), -isize::MAX);
+    assert_eq!(res.denominator().abs(), isize::MAX - 1);
+}
+
+#[test]
+fn it_divides_near_max() {
+    let a = Rational::new(isize::MAX, 2);
+    let b = Rational::new(isize::MAX, 4);
+    assert_eq!(a / b, 2.into());
+
+    let a = Rational::new(isize::MIN, isize::MAX);
+    let b = Rational::new(isize::MIN, 3);
+    let res = a / b;
+    assert_eq!(res.numerator() * res.denominator().signum(), 3);
+    assert_eq!(res.denominator().abs(), isize::MAX);
+}
+
+#[test]
+fn it_adds_near_max() {
+    let a = Rational::new(isize::MAX, 2);
+    let b = Rational::new(-(isize::MAX - 2), 2);
+    check_addition(a, b, 1.into());
+
+    let a = Rational::new(isize::MAX - 1, isize::MAX);
+    let b = Rational::new(1, isize::MAX);
+    check_addition(a, b, 1.into());
+
+    // The intermediate numerator overflows, but the reduced result fits
+    let a = Rational::new(isize::MAX, 3);
+    let b = Rational::new(isize::MAX - 2, 3);
+    check_addition(a, b, (2 * ((isize::MAX - 1) / 3)).into());
+}
+
+#[test]
+fn it_subtracts_near_max() {
+    let a = Rational::new(isize::MAX, 2);
+    let b = Rational::new(isize::MAX - 2, 2);
+    check_subtraction(a, b, 1.into());
+
+    let a = Rational::new(isize::MAX, 3);
+    let b = Rational::new(-(isize::MAX - 2), 3);
+    check_subtraction(a, b, (2 * ((isize::MAX - 1) / 3)).into());
+
+    let a = Rational::new(isize::MIN, isize::MAX);
+    let b = Rational::new(isize::MIN + 1, isize::MAX);
+    check_subtraction(a, b, Rational::new(-1, isize::MAX));
+}
+
+#[should_panic(expected = "attempt to add with overflow")]
+#[test]
+fn it_panics_when_result_does_not_fit() {
+    let a = Rational::new(isize::MAX, 3);
+    let _res = a + a;
+}
+
+#[test]
+fn it_operates_on_references() {
+    check_references(Rational::new(1, 2), Rational::new(-2, 3));
+    check_references(Ratio::<i8>::new(-7, 8), Ratio::new(3, 4));
+    check_references(
+        BigRational::new(BigInt::from(1), BigInt::from(2)),
+        BigRational::new(BigInt::from(1), BigInt::from(3)),
+    );
+}
+
+#[test]
+fn it_assigns() {
+    let mut value = Rational::new(1, 2);
+    value += Rational::new(1, 3);
+    assert_eq!(value, Rational::new(5, 6));
+    value -= &Rational::new(1, 6);
+    assert_eq!(value, Rational::new(2, 3));
+    value *= Rational::new(-3, 4);
+    assert_eq!(value, Rational::new(-1, 2));
+    value /= &Rational::new(1, 4);
+    assert_eq!(value, Rational::new(-2, 1));
+
+    value += 3;
+    assert_eq!(value, Rational::new(1, 1));
+    value -= 4;
+    assert_eq!(value, Rational::new(-3, 1));
+    value *= 2;
+    assert_eq!(value, Rational::new(-6, 1));
+    value /= -4;
+    assert_eq!(value, Rational::new(3, 2));
+
+    let sum = (1..=4).fold(Rational::new(0, 1), |mut sum, n| {
+        sum += Rational::new(1, n);
+        sum
+    });
+    assert_eq!(sum, Rational::new(25, 12));
+
+    let mut big = BigRational::from(BigInt::from(1));
+    big /= BigInt::from(3);
+    big += &Ratio::new(BigInt::from(1), BigInt::from(6));
+    assert_eq!(big, Ratio::new(BigInt::from(1), BigInt::from(2)));
+}
+
+#[test]
+fn it_operates_with_integers_on_both_sides() {
+    let a = Rational::new(3, 4);
+    assert_eq!(a + 1, Rational::new(7, 4));
+    assert_eq!(1 + a, Rational::new(7, 4));
+    assert_eq!(a - 1, Rational::new(-1, 4));
+    assert_eq!(1 - a, Rational::new(1, 4));
+    assert_eq!(a * 2, Rational::new(3, 2));
+    assert_eq!(2 * a, Rational::new(3, 2));
+    assert_eq!(a / 3, Rational::new(1, 4));
+    assert_eq!(3 / a, Rational::new(4, 1));
+    assert_eq!(&a * 4, Rational::new(3, 1));
+    assert_eq!(4 * &a, Rational::new(3, 1));
+
+    assert_eq!(Ratio::<i8>::new(1, 2) * 2i8, Ratio::new(1, 1));
+    assert_eq!(2i8 * Ratio::<i8>::new(1, 2), Ratio::new(1, 1));
+    assert_eq!(5i16 - Ratio::<i16>::new(1, 2), Ratio::new(9, 2));
+    assert_eq!(5i32 / Ratio::<i32>::new(5, 2), Ratio::new(2, 1));
+    assert_eq!(1i64 + Ratio::<i64>::new(1, 2), Ratio::new(3, 2));
+    assert_eq!(i128::MAX / Ratio::new(i128::MAX, 2), Ratio::new(2, 1));
+    assert_eq!(
+        BigInt::from(2) * BigRational::new(BigInt::from(1), BigInt::from(4)),
+        Ratio::new(BigInt::from(1), BigInt::from(2))
+    );
+    assert_eq!(
+        BigRational::new(BigInt::from(1), BigInt::from(4)) - BigInt::from(1),
+        Ratio::new(BigInt::from(-3), BigInt::from(4))
+    );
+}
+
+#[test]
+// Borrowing the operands is what's being tested
+#[allow(clippy::op_ref)]
+fn it_operates_with_borrowed_integers() {
+    let a = Rational::new(3, 4);
+    assert_eq!(a * &2, Rational::new(3, 2));
+    assert_eq!(&a - &1, Rational::new(-1, 4));
+    assert_eq!(&2 * a, Rational::new(3, 2));
+    assert_eq!(&3 / &a, Rational::new(4, 1));
+    assert_eq!(&5 % a, Rational::new(1, 2));
+
+    let mut b = a;
+    b += &1;
+    b *= &4;
+    assert_eq!(b, Rational::new(7, 1));
+
+    let two = BigInt::from(2);
+    let half = BigRational::new(BigInt::from(1), BigInt::from(2));
+    assert_eq!(&half * &two, Ratio::from(BigInt::from(1)));
+    assert_eq!(&two - &half, Ratio::new(BigInt::from(3), BigInt::from(2)));
+}
+
+#[test]
+#[should_panic(expected = "Can't divide by zero")]
+fn it_panics_when_dividing_by_integer_zero() {
+    let _ = Rational::new(1, 2) / 0;
+}
+
+#[test]
+#[should_panic(expected = "attempt to multiply with overflow")]
+fn it_panics_when_assignment_overflows() {
+    let mut value = Ratio::<i8>::new(100, 1);
+    value *= 2;
+}
+
+/// Checks that every combination of owned and borrowed operands agrees with the owned one.
+fn check_references<T: Integer>(a: Ratio<T>, b: Ratio<T>) {
+    let sum = a.clone() + b.clone();
+    assert_eq!(&a + &b, sum);
+    assert_eq!(&a + b.clone(), sum);
+    assert_eq!(a.clone() + &b, sum);
+
+    let difference = a.clone() - b.clone();
+    assert_eq!(&a - &b, difference);
+    assert_eq!(&a - b.clone(), difference);
+    assert_eq!(a.clone() - &b, difference);
+
+    let product = a.clone() * b.clone();
+    assert_eq!(&a * &b, product);
+    assert_eq!(&a * b.clone(), product);
+    assert_eq!(a.clone() * &b, product);
+
+    let quotient = a.clone() / b.clone();
+    assert_eq!(&a / &b, quotient);
+    assert_eq!(&a / b.clone(), quotient);
+    assert_eq!(a.clone() / &b, quotient);
+
+    assert_eq!(-&b, -b.clone());
+}
+
+#[test]
+fn it_calculates_remainders_with_the_sign_of_the_dividend() {
+    let cases = [
+        ((7, 2), (3, 2), (2, (1, 2))),
+        ((-7, 2), (3, 2), (-2, (-1, 2))),
+        ((7, 2), (-3, 2), (-2, (1, 2))),
+        ((-7, 2), (-3, 2), (2, (-1, 2))),
+        ((1, 2), (1, 3), (1, (1, 6))),
+        ((3, 1), (3, 4), (4, (0, 1))),
+        ((0, 1), (5, 7), (0, (0, 1))),
+    ];
+    for ((a, b), (c, d), (quotient, (p, q))) in cases {
+        let (a, b) = (Rational::new(a, b), Rational::new(c, d));
+        let remainder = Rational::new(p, q);
+        assert_eq!(a.div_rem(b), (quotient, remainder), "{a} {b}");
+        assert_eq!(a % b, remainder, "{a} {b}");
+
+        let mut assigned = a;
+        assigned %= b;
+        assert_eq!(assigned, remainder);
+    }
+
+    assert_eq!(Rational::new(7, 2) % 2, Rational::new(3, 2));
+    assert_eq!(7 % Rational::new(3, 2), Rational::new(1, 1));
+    assert_eq!(-7 % Rational::new(3, 2), Rational::new(-1, 1));
+}
+
+#[test]
+fn it_divides_euclidean() {
+    let cases = [
+        ((7, 2), (3, 2), (2, (1, 2))),
+        ((-7, 2), (3, 2), (-3, (1, 1))),
+        ((7, 2), (-3, 2), (-2, (1, 2))),
+        ((-7, 2), (-3, 2), (3, (1, 1))),
+        ((-1, 3), (1, 1), (-1, (2, 3))),
+        ((-3, 1), (3, 4), (-4, (0, 1))),
+    ];
+    for ((a, b), (c, d), (quotient, (p, q))) in cases {
+        let (a, b) = (Rational::new(a, b), Rational::new(c, d));
+        assert_eq!(a.div_euclid(b), quotient, "{a} {b}");
+        assert_eq!(a.rem_euclid(b), Rational::new(p, q), "{a} {b}");
+    }
+}
+
+#[test]
+fn it_divides_integers_like_primitives() {
+    for a in -20i64..=20 {
+        for b in (-6i64..=6).filter(|&b| b != 0) {
+            let (x, y) = (Ratio::from(a), Ratio::from(b));
+            assert_eq!(x.div_rem(y), (a / b, Ratio::from(a % b)), "{a} {b}");
+            assert_eq!(x.div_euclid(y), a.div_euclid(b), "{a} {b}");
+            assert_eq!(x.rem_euclid(y), Ratio::from(a.rem_euclid(b)), "{a} {b}");
+        }
+    }
+}
+
+#[test]
+fn remainders_satisfy_the_division_identity() {
+    for p in -30i64..=30 {
+        for q in 1..=7 {
+            for (r, s) in [(1, 2), (-2, 3), (5, 4), (-7, 5), (3, 1)] {
+                let (a, b) = (Ratio::new(p, q), Ratio::new(r, s));
+
+                let (quotient, remainder) = a.div_rem(b);
+                assert_eq!(b * quotient + remainder, a);
+                assert!(remainder.numerator() == 0 || (remainder.numerator() < 0) == (p < 0));
+                assert!(abs(remainder) < abs(b));
+
+                let quotient = a.div_euclid(b);
+                let remainder = a.rem_euclid(b);
+                assert_eq!(b * quotient + remainder, a);
+                assert!(remainder >= Ratio::new(0, 1) && remainder < abs(b));
+            }
+        }
+    }
+}
+
+#[test]
+fn it_calculates_remainders_when_the_exact_quotient_overflows() {
+    // -128 / (13/12) = -1536/13 doesn't fit into i8, but its integer part and the remainder do
+    let a = Ratio::<i8>::new(-128, 1);
+    let b = Ratio::<i8>::new(13, 12);
+    assert_eq!(a.div_rem(b), (-118, Ratio::new(-1, 6)));
+    assert_eq!(a % b, Ratio::new(-1, 6));
+    assert_eq!(a.div_euclid(b), -119);
+    assert_eq!(a.rem_euclid(b), Ratio::new(11, 12));
+}
+
+#[test]
+#[should_panic(expected = "attempt to calculate the remainder with overflow")]
+fn it_panics_when_the_remainder_does_not_fit() {
+    // The remainder is 2/15875
+    let _ = Ratio::<i8>::new(1, 125) % Ratio::new(1, 127);
+}
+
+#[test]
+#[should_panic(expected = "attempt to calculate the remainder with overflow")]
+fn it_panics_when_the_integer_quotient_does_not_fit() {
+    let _ = Ratio::<i8>::new(127, 1).div_rem(Ratio::new(1, 2));
+}
+
+#[test]
+#[should_panic(expected = "attempt to calculate the remainder with overflow")]
+fn it_panics_when_the_euclidean_quotient_does_not_fit() {
+    let _ = Ratio::<i8>::new(-127, 1).div_euclid(Ratio::new(1, 127));
+}
+
+#[test]
+fn it_calculates_remainders_when_the_integer_quotient_overflows() {
+    let a = Ratio::<i8>::new(127, 1);
+    assert_eq!(a % Ratio::new(1, 2), Ratio::new(0, 1));
+    assert_eq!(a.rem_euclid(Ratio::new(-1, 2)), Ratio::new(0, 1));
+
+    let a = Ratio::<i8>::new(-127, 1);
+    assert_eq!(a % Ratio::new(1, 127), Ratio::new(0, 1));
+    assert_eq!(a % Ratio::new(2, 5), Ratio::new(-1, 5));
+    assert_eq!(a.rem_euclid(Ratio::new(2, 5)), Ratio::new(1, 5));
+    assert_eq!(a.rem_euclid(Ratio::new(-2, 5)), Ratio::new(1, 5));
+}
+
+#[test]
+#[should_panic(expected = "Can't divide by zero")]
+fn it_panics_on_zero_modulus() {
+    let _ = Rational::new(1, 2) % Rational::new(0, 1);
+}
+
+#[test]
+fn it_calculates_euclidean_remainders_when_the_truncating_one_overflows() {
+    // The truncating remainder is -29/301, which doesn't fit into i8
+    let a = Ratio::<i8>::new(-127, 43);
+    let b = Ratio::<i8>::new(-1, 7);
+    assert_eq!(a.rem_euclid(b), Ratio::new(2, 43));
+    assert_eq!(a.div_euclid(b), 21);
+}
+
+#[test]
+fn euclidean_division_agrees_with_big_rationals() {
+    let values: Vec<Ratio<i8>> = [-128, -127, -101, -64, -1, 0, 1, 63, 100, 127]
+        .into_iter()
+        .flat_map(|p| [1, 2, 7, 43, 127].map(|q| Ratio::new(p, q)))
+        .collect();
+    for &a in &values {
+        for &b in values.iter().filter(|b| b.numerator() != 0) {
+            let (big_a, big_b) = (BigRational::from(a), BigRational::from(b));
+            let remainder = Ratio::<i8>::try_from(big_a.clone().rem_euclid(big_b.clone()));
+            if let Ok(remainder) = remainder {
+                assert_eq!(a.rem_euclid(b), remainder, "{a} {b}");
+            }
+            let quotient = i8::try_from(big_a.div_euclid(big_b));
+            if let (Ok(quotient), Ok(remainder)) = (quotient, remainder) {
+                assert_eq!(
+                    (a.div_euclid(b), a.rem_euclid(b)),
+                    (quotient, remainder),
+                    "{a} {b}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "Can't divide by zero")]
+fn it_panics_on_zero_euclidean_divisor() {
+    let _ = Rational::new(1, 2).rem_euclid(Rational::new(0, 1));
+}
+
+fn abs(value: Ratio<i64>) -> Ratio<i64> {
+    if value < Ratio::new(0, 1) {
+        -value
+    } else {
+        value
+    }
+}
+
 fn check_addition(a: Rational, b: Rational, res: Rational) {
     assert_eq!(a + b, res);
     assert_eq!(b + a, res);
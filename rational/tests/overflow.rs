@@ -0,0 +1,120 @@
+use rational::*;
+
+#[test]
+fn checked_operations_work_without_overflow() {
+    let a: Ratio<i16> = Ratio::new(3, 4);
+    let b: Ratio<i16> = Ratio::new(-1, 6);
+
+    assert_eq!(a.checked_add(b), Some(Ratio::new(7, 12)));
+    assert_eq!(a.checked_sub(b), Some(Ratio::new(11, 12)));
+    assert_eq!(a.checked_mul(b), Some(Ratio::new(-1, 8)));
+    assert_eq!(a.checked_div(b), Some(Ratio::new(-9, 2)));
+    assert_eq!(a.checked_neg(), Some(Ratio::new(-3, 4)));
+}
+
+#[test]
+fn checked_operations_detect_overflow() {
+    let big: Ratio<i8> = Ratio::from(100);
+    let small: Ratio<i8> = Ratio::new(1, 100);
+
+    assert_eq!(big.checked_add(big), None);
+    assert_eq!((-big).checked_sub(big), None);
+    assert_eq!(big.checked_mul(big), None);
+    assert_eq!(big.checked_div(small), None);
+    assert_eq!(small.checked_mul(small), None);
+}
+
+#[test]
+fn checked_div_by_zero_is_none() {
+    let a: Rational = Rational::new(1, 2);
+    assert_eq!(a.checked_div(0.into()), None);
+}
+
+#[test]
+fn checked_neg_detects_overflow() {
+    let a: Ratio<i8> = Ratio::new(i8::MIN, 3);
+    assert_eq!(a.checked_neg(), None);
+}
+
+#[test]
+fn overflowing_operations_report_overflow() {
+    let a: Ratio<i8> = Ratio::new(1, 2);
+    assert_eq!(a.overflowing_add(a), (Ratio::from(1), false));
+    assert_eq!(a.overflowing_sub(a), (Ratio::from(0), false));
+    assert_eq!(a.overflowing_mul(a), (Ratio::new(1, 4), false));
+    assert_eq!(a.overflowing_div(a), (Ratio::from(1), false));
+
+    let big: Ratio<i8> = Ratio::from(-100);
+    assert_eq!(big.overflowing_add(big), (Ratio::from(i8::MIN), true));
+    assert_eq!(big.overflowing_sub(-big), (Ratio::from(i8::MIN), true));
+    assert_eq!(big.overflowing_mul(big), (Ratio::from(i8::MAX), true));
+    assert_eq!(
+        big.overflowing_div(Ratio::new(1, 2)),
+        (Ratio::from(i8::MIN), true)
+    );
+}
+
+#[test]
+fn saturating_operations_clamp_to_bounds() {
+    let a: Ratio<i64> = Ratio::from(i64::MAX);
+    let b: Ratio<i64> = Ratio::new(1, 2);
+
+    assert_eq!(a.saturating_add(a), Ratio::from(i64::MAX));
+    assert_eq!((-a).saturating_sub(a), Ratio::from(i64::MIN));
+    assert_eq!(a.saturating_mul(-a), Ratio::from(i64::MIN));
+    assert_eq!(a.saturating_div(b), Ratio::from(i64::MAX));
+}
+
+#[test]
+fn saturating_operations_approximate_within_bounds() {
+    let a: Ratio<i8> = Ratio::new(1, 100);
+    assert_eq!(a.saturating_mul(a), Ratio::new(0, 1));
+
+    let a: Ratio<i8> = Ratio::new(50, 99);
+    let b: Ratio<i8> = Ratio::new(1, 101);
+    let res = a.saturating_add(b);
+    assert_eq!((res.numerator(), res.denominator()), (52, 101));
+
+    let a: Ratio<i8> = Ratio::new(-50, 99);
+    let res = a.saturating_sub(b);
+    assert_eq!((res.numerator(), res.denominator()), (-52, 101));
+}
+
+#[test]
+fn saturating_operations_reach_min() {
+    let a: Ratio<i8> = Ratio::new(-127, 1);
+    assert_eq!(a.saturating_add(Ratio::new(-9, 10)), Ratio::from(i8::MIN));
+    assert_eq!(a.saturating_sub(Ratio::new(1, 10)), Ratio::from(-127));
+    assert_eq!(a.saturating_sub(Ratio::new(1, 2)), Ratio::from(-127));
+
+    let a: Ratio<i64> = Ratio::new(i64::MIN + 1, 1);
+    assert_eq!(
+        a.overflowing_sub(Ratio::new(1, 2)),
+        (Ratio::from(i64::MIN + 1), true)
+    );
+    assert_eq!(
+        a.overflowing_sub(Ratio::new(2, 3)),
+        (Ratio::from(i64::MIN), true)
+    );
+}
+
+#[test]
+fn big_rational_never_saturates() {
+    let a = BigRational::from(Ratio::from(i128::MAX));
+    let res = a.clone().saturating_mul(a.clone());
+    assert_eq!(res, a.clone() * a);
+}
+
+#[should_panic(expected = "attempt to add with overflow")]
+#[test]
+fn operators_panic_on_overflow() {
+    let a: Ratio<i8> = Ratio::from(100);
+    let _res = a + a;
+}
+
+#[should_panic(expected = "Can't divide by zero")]
+#[test]
+fn saturating_div_by_zero_panics() {
+    let a: Rational = Rational::new(1, 2);
+    let _res = a.saturating_div(0.into());
+}
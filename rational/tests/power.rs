@@ -0,0 +1,203 @@
+use rational::*;
+
+#[test]
+fn it_raises_to_integer_powers() {
+    let value = Rational::new(-2, 3);
+    assert_eq!(value.pow(0), Rational::new(1, 1));
+    assert_eq!(value.pow(1), value);
+    assert_eq!(value.pow(2), Rational::new(4, 9));
+    assert_eq!(value.pow(3), Rational::new(-8, 27));
+    assert_eq!(value.pow(-1), Rational::new(-3, 2));
+    assert_eq!(value.pow(-3), Rational::new(-27, 8));
+    assert_eq!(Rational::new(0, 1).pow(0), Rational::new(1, 1));
+    assert_eq!(Rational::new(0, 1).pow(5), Rational::new(0, 1));
+    assert_eq!(Rational::new(1, 1).pow(i32::MIN), Rational::new(1, 1));
+    assert_eq!(Rational::new(-1, 1).pow(i32::MAX), Rational::new(-1, 1));
+
+    let big = BigRational::new(BigInt::from(3), BigInt::from(2)).pow(100);
+    assert_eq!(
+        big.numerator(),
+        BigInt::from(3u128.pow(40)) * BigInt::from(3u128.pow(60))
+    );
+    assert_eq!(big.denominator(), BigInt::from(2u128.pow(100)));
+}
+
+#[test]
+fn it_agrees_with_repeated_multiplication() {
+    for (p, q) in [(3, 7), (-5, 2), (1, 9), (-11, 13)] {
+        let value = Ratio::<i128>::new(p, q);
+        let mut expected = Ratio::new(1, 1);
+        for exp in 0..20 {
+            assert_eq!(value.pow(exp), expected, "{value}^{exp}");
+            assert_eq!(
+                value.pow(-exp),
+                Ratio::new(1, 1) / expected,
+                "{value}^-{exp}"
+            );
+            expected *= value;
+        }
+    }
+}
+
+#[test]
+fn it_detects_overflow() {
+    assert_eq!(
+        Ratio::<i8>::new(2, 1).checked_pow(6),
+        Some(Ratio::new(64, 1))
+    );
+    assert_eq!(Ratio::<i8>::new(2, 1).checked_pow(7), None);
+    assert_eq!(
+        Ratio::<i8>::new(-2, 1).checked_pow(7),
+        Some(Ratio::new(-128, 1))
+    );
+    assert_eq!(Ratio::<i8>::new(-2, 1).checked_pow(8), None);
+    assert_eq!(
+        Ratio::<i8>::new(1, 11).checked_pow(2),
+        Some(Ratio::new(1, 121))
+    );
+    assert_eq!(Ratio::<i8>::new(1, 12).checked_pow(2), None);
+    assert_eq!(
+        Ratio::<i8>::new(-1, 2).checked_pow(-7),
+        Some(Ratio::new(-128, 1))
+    );
+    assert_eq!(Ratio::<i8>::new(-128, 1).checked_pow(-1), None);
+    assert_eq!(Ratio::<i8>::new(0, 1).checked_pow(-2), None);
+    assert_eq!(
+        Ratio::<i128>::new(3, 1).checked_pow(40),
+        Some(Ratio::new(3i128.pow(40), 1))
+    );
+    assert_eq!(Ratio::<i64>::new(3, 1).checked_pow(40), None);
+}
+
+#[test]
+#[should_panic(expected = "attempt to multiply with overflow")]
+fn it_panics_when_the_power_overflows() {
+    let _ = Ratio::<i16>::new(10, 7).pow(5);
+}
+
+#[test]
+#[should_panic(expected = "Can't divide by zero")]
+fn it_panics_on_negative_powers_of_zero() {
+    let _ = Rational::new(0, 1).pow(-1);
+}
+
+#[test]
+fn it_raises_to_rational_powers_when_the_root_is_rational() {
+    let value = Rational::new(8, 27);
+    assert_eq!(
+        value.try_pow_rational(Rational::new(1, 3)),
+        Some(Rational::new(2, 3))
+    );
+    assert_eq!(
+        value.try_pow_rational(Rational::new(2, 3)),
+        Some(Rational::new(4, 9))
+    );
+    assert_eq!(
+        value.try_pow_rational(Rational::new(-2, 3)),
+        Some(Rational::new(9, 4))
+    );
+    assert_eq!(
+        value.try_pow_rational(Rational::new(4, 6)),
+        Some(Rational::new(4, 9))
+    );
+    assert_eq!(
+        value.try_pow_rational(Rational::new(2, 1)),
+        Some(Rational::new(64, 729))
+    );
+    assert_eq!(
+        value.try_pow_rational(Rational::new(0, 1)),
+        Some(Rational::new(1, 1))
+    );
+    assert_eq!(value.try_pow_rational(Rational::new(1, 2)), None);
+
+    assert_eq!(
+        Rational::new(-32, 243).try_pow_rational(Rational::new(3, 5)),
+        Some(Rational::new(-8, 27))
+    );
+    assert_eq!(
+        Rational::new(-4, 9).try_pow_rational(Rational::new(1, 2)),
+        None
+    );
+    assert_eq!(
+        Rational::new(2, 1).try_pow_rational(Rational::new(1, 2)),
+        None
+    );
+    assert_eq!(
+        Rational::new(4, 3).try_pow_rational(Rational::new(1, 2)),
+        None
+    );
+
+    assert_eq!(
+        Ratio::<i8>::new(-128, 1).try_pow_rational(Ratio::new(1, 7)),
+        Some(Ratio::new(-2, 1))
+    );
+    assert_eq!(
+        Ratio::<i8>::new(-128, 1).try_pow_rational(Ratio::new(1, 1)),
+        Some(Ratio::new(-128, 1))
+    );
+    assert_eq!(
+        Ratio::<i8>::new(-128, 1).try_pow_rational(Ratio::new(-1, 1)),
+        None
+    );
+    assert_eq!(
+        Ratio::<i8>::new(4, 1).try_pow_rational(Ratio::new(7, 2)),
+        None
+    );
+}
+
+#[test]
+fn it_raises_trivial_bases_to_any_rational_power() {
+    let huge = Ratio::new(i64::MAX, i64::MAX - 2);
+    let zero = Ratio::<i64>::new(0, 1);
+    let one = Ratio::<i64>::new(1, 1);
+    let minus_one = Ratio::<i64>::new(-1, 1);
+
+    assert_eq!(zero.try_pow_rational(huge), Some(zero));
+    assert_eq!(zero.try_pow_rational(-huge), None);
+    assert_eq!(zero.try_pow_rational(zero), Some(one));
+    assert_eq!(one.try_pow_rational(-huge), Some(one));
+    assert_eq!(minus_one.try_pow_rational(huge), Some(minus_one));
+    assert_eq!(
+        minus_one.try_pow_rational(Ratio::new(i64::MAX - 1, i64::MAX)),
+        Some(one)
+    );
+    assert_eq!(
+        minus_one.try_pow_rational(Ratio::new(i64::MAX, i64::MAX - 1)),
+        None
+    );
+    assert_eq!(
+        minus_one.try_pow_rational(Ratio::new(i64::MAX - 1, 1)),
+        Some(one)
+    );
+    assert_eq!(Ratio::<i64>::new(2, 1).try_pow_rational(huge), None);
+    assert_eq!(
+        Ratio::<i64>::new(2, 1).try_pow_rational(Ratio::new(i64::MAX, 1)),
+        None
+    );
+}
+
+#[test]
+fn rational_powers_invert_integer_powers() {
+    for p in -12i64..=12 {
+        for q in 1..=12 {
+            let base = Ratio::new(p, q);
+            for degree in 1..=5 {
+                let power = base.pow(degree);
+                let root = power.try_pow_rational(Ratio::new(1, degree as i64));
+                if degree % 2 == 0 {
+                    assert_eq!(root.map(|root| root.pow(2)), Some(base.pow(2)), "{power}");
+                } else {
+                    assert_eq!(root, Some(base), "{power}");
+                }
+                let almost = power + Ratio::new(1, 1 << 20);
+                if p != 0 && degree > 1 {
+                    assert_eq!(
+                        almost.try_pow_rational(Ratio::new(1, degree as i64)),
+                        None,
+                        "{almost}"
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use rational::*;
+
+#[test]
+fn it_parses_binary() {
+    assert_eq!(
+        Rational::from_str_radix("0.(01)", 2),
+        Ok(Rational::new(1, 3))
+    );
+    assert_eq!(
+        Rational::from_str_radix("-101.1", 2),
+        Ok(Rational::new(-11, 2))
+    );
+    assert_eq!(
+        Rational::from_str_radix("0.0(1)", 2),
+        Ok(Rational::new(1, 2))
+    );
+    assert_eq!(
+        Rational::from_str_radix("11/110", 2),
+        Ok(Rational::new(1, 2))
+    );
+}
+
+#[test]
+fn it_parses_other_radices() {
+    assert_eq!(Rational::from_str_radix("0.4", 8), Ok(Rational::new(1, 2)));
+    assert_eq!(
+        Rational::from_str_radix("0.(3)", 8),
+        Ok(Rational::new(3, 7))
+    );
+    assert_eq!(
+        Rational::from_str_radix("Ff.8", 16),
+        Ok(Rational::new(511, 2))
+    );
+    assert_eq!(
+        Rational::from_str_radix("0.(5)", 16),
+        Ok(Rational::new(1, 3))
+    );
+    assert_eq!(Rational::from_str_radix("1e", 16), Ok(Rational::new(30, 1)));
+    assert_eq!(
+        Rational::from_str_radix("z.i", 36),
+        Ok(Rational::new(71, 2))
+    );
+    assert_eq!(
+        Rational::from_str_radix("-1 1/2", 3),
+        Ok(Rational::new(-3, 2))
+    );
+    assert_eq!(Rational::from_str_radix("0.1", 3), Ok(Rational::new(1, 3)));
+}
+
+#[test]
+fn it_rejects_digits_outside_the_radix() {
+    let error = Ratio::<isize>::from_str_radix("0.12", 2).unwrap_err();
+    assert_eq!(
+        (error.kind(), error.position()),
+        (RationalErrorKind::InvalidChar, 3)
+    );
+
+    let value = Ratio::<isize>::from_str_radix("1e5", 10);
+    assert_eq!(value, Ok(Rational::new(100000, 1)));
+
+    // Scientific notation is only available in radix 10
+    let error = Ratio::<isize>::from_str_radix("1e5", 8).unwrap_err();
+    assert_eq!(
+        (error.kind(), error.position()),
+        (RationalErrorKind::InvalidChar, 1)
+    );
+
+    let error = Ratio::<i8>::from_str_radix("10000000", 2).unwrap_err();
+    assert_eq!(error.kind(), RationalErrorKind::Overflow);
+}
+
+#[test]
+#[should_panic]
+fn it_panics_on_invalid_radix() {
+    let _ = Ratio::<isize>::from_str_radix("1", 37);
+}
+
+#[test]
+fn it_formats_in_radix() {
+    assert_eq!(Rational::new(1, 3).to_string_radix(2), "0.(01)");
+    assert_eq!(Rational::new(-11, 2).to_string_radix(2), "-101.1");
+    assert_eq!(Rational::new(511, 2).to_string_radix(16), "ff.8");
+    assert_eq!(Rational::new(1, 3).to_string_radix(16), "0.(5)");
+    assert_eq!(Rational::new(1, 6).to_string_radix(16), "0.2(a)");
+    assert_eq!(Rational::new(0, 1).to_string_radix(7), "0");
+    assert_eq!(Rational::new(71, 2).to_string_radix(36), "z.i");
+    assert_eq!(Rational::new(-25, 7).to_string_radix(10), "-3.(571428)");
+    assert_eq!(Ratio::new(i8::MIN, 1).to_string_radix(2), "-10000000");
+}
+
+#[test]
+fn radix_strings_round_trip() {
+    let values: Vec<Ratio<i16>> = (-20..20)
+        .flat_map(|p| (1..20).map(move |q| Ratio::new(p * 37, q)))
+        .collect();
+    for radix in 2..=36 {
+        for &value in &values {
+            let text = value.to_string_radix(radix);
+            assert_eq!(Ratio::from_str_radix(&text, radix), Ok(value), "{text}");
+        }
+    }
+
+    let a: BigRational = "-123456789012345678901234567890.(3)".parse().unwrap();
+    for radix in [2, 7, 16, 36] {
+        let text = a.to_string_radix(radix);
+        assert_eq!(BigRational::from_str_radix(&text, radix), Ok(a.clone()));
+    }
+}
@@ -1,3 +1,6 @@
+mod common;
+
+use common::{error_of, xorshift};
 use rational::*;
 
 #[test]
@@ -58,44 +61,318 @@ fn it_parses_all_kinds_of_strings() {
 #[test]
 fn doesnt_parse_empty_repeating() {
     let a: Result<Rational, _> = "1.34()".parse();
-    assert_eq!(a, Err("Error parsing string"));
+    assert_eq!(error_of(a), (RationalErrorKind::EmptyRepeating, 4));
 }
 
 #[test]
 fn doesnt_parse_digits_after_repeating() {
     let a: Result<Rational, _> = "1.34(3)12".parse();
-    assert_eq!(a, Err("Error parsing string"));
+    assert_eq!(error_of(a), (RationalErrorKind::InvalidChar, 7));
 }
 
 #[test]
 fn doesnt_parse_anything_after_repeating() {
     let a: Result<Rational, _> = "1.34(3)--3".parse();
-    assert_eq!(a, Err("Error parsing string"));
+    assert_eq!(error_of(a), (RationalErrorKind::InvalidChar, 7));
 }
 
 #[test]
 fn doesnt_parse_incorrect_repeating_part() {
     let a: Result<Rational, _> = "1.34(3.2)".parse();
-    assert_eq!(a, Err("Error parsing string"));
+    assert_eq!(error_of(a), (RationalErrorKind::InvalidChar, 6));
 }
 
 #[test]
 fn doesnt_parse_all_kinds_of_incorrect_strings() {
     let a: Result<Rational, _> = "0(3)".parse();
-    assert_eq!(a, Err("Error parsing string"));
+    assert_eq!(error_of(a), (RationalErrorKind::InvalidChar, 1));
 
     let a: Result<Rational, _> = "0(3).5".parse();
-    assert_eq!(a, Err("Error parsing string"));
+    assert_eq!(error_of(a), (RationalErrorKind::InvalidChar, 1));
 
     let a: Result<Rational, _> = "0.(3".parse();
-    assert_eq!(a, Err("Error parsing string"));
+    assert_eq!(error_of(a), (RationalErrorKind::UnclosedRepeating, 2));
 
     let a: Result<Rational, _> = "0.3)".parse();
-    assert_eq!(a, Err("Error parsing string"));
+    assert_eq!(error_of(a), (RationalErrorKind::InvalidChar, 3));
 }
 
 #[test]
 fn doesnt_parse_no_digits_number() {
     let a: Result<Rational, _> = ".()".parse();
-    assert_eq!(a, Err("Error parsing string"));
+    assert_eq!(error_of(a), (RationalErrorKind::EmptyRepeating, 1));
+}
+
+#[test]
+fn it_formats_shortest_repeating_decimal() {
+    assert_eq!(Rational::new(1, 3).to_repeating_decimal(), "0.(3)");
+    assert_eq!(Rational::new(-1, 30).to_repeating_decimal(), "-0.0(3)");
+    assert_eq!(Rational::new(8, 195).to_repeating_decimal(), "0.0(410256)");
+    assert_eq!(Rational::new(3, 4).to_repeating_decimal(), "0.75");
+    assert_eq!(Rational::new(-12, 1).to_repeating_decimal(), "-12");
+    assert_eq!(Rational::new(0, 5).to_repeating_decimal(), "0");
+    assert_eq!(
+        Rational::new(1, 97).to_repeating_decimal(),
+        "0.(010309278350515463917525773195876288659793814432989690721649484536082474226804123711340206185567)"
+    );
+}
+
+#[test]
+fn it_iterates_over_digits_with_period() {
+    let digits: Vec<_> = Rational::new(1, 7 * 4).decimal_digits().collect();
+    assert_eq!(
+        digits,
+        [
+            Digit::NonRepeating(0),
+            Digit::NonRepeating(3),
+            Digit::Repeating(5),
+            Digit::Repeating(7),
+            Digit::Repeating(1),
+            Digit::Repeating(4),
+            Digit::Repeating(2),
+            Digit::Repeating(8),
+        ]
+    );
+
+    let digits: Vec<_> = Rational::new(-9, 8).decimal_digits().collect();
+    assert_eq!(
+        digits,
+        [
+            Digit::NonRepeating(1),
+            Digit::NonRepeating(2),
+            Digit::NonRepeating(5)
+        ]
+    );
+
+    assert_eq!(Rational::new(4, 2).decimal_digits().next(), None);
+}
+
+#[test]
+fn repeating_decimals_round_trip() {
+    fn check<T: Integer + TryFrom<i64>>() {
+        for (p, q) in sample_pairs() {
+            let (Ok(p), Ok(q)) = (T::try_from(p), T::try_from(q)) else {
+                continue;
+            };
+            if q <= T::zero() {
+                continue;
+            }
+            let value = Ratio::new(p, q);
+            let text = value.to_repeating_decimal();
+            assert_eq!(text.parse::<Ratio<T>>(), Ok(value.clone()), "{text}");
+        }
+    }
+
+    check::<i8>();
+    check::<i16>();
+    check::<i32>();
+    check::<i64>();
+    check::<isize>();
+    check::<BigInt>();
+}
+
+#[test]
+fn repeating_decimals_round_trip_at_bounds() {
+    let values: [Ratio<i8>; 4] = [
+        Ratio::new(-128, 127),
+        Ratio::new(127, 126),
+        Ratio::new(-1, 127),
+        Ratio::new(-128, 1),
+    ];
+    for value in values {
+        let text = value.to_repeating_decimal();
+        assert_eq!(text.parse(), Ok(value), "{text}");
+    }
+
+    let value = Ratio::new(i64::MIN, 1 << 40);
+    assert_eq!(value.to_repeating_decimal().parse(), Ok(value));
+
+    let value = Ratio::new(i64::MAX, 3 * 1024);
+    assert_eq!(value.to_repeating_decimal().parse(), Ok(value));
+}
+
+fn sample_pairs() -> Vec<(i64, i64)> {
+    let mut next = xorshift(0x2545F4914F6CDD1D);
+
+    (0..300)
+        .map(|_| {
+            // Small denominators keep the periods short enough to be cheap to expand
+            let p = (next() as i64) >> (next() % 56);
+            let q = (next() % 500) as i64 + 1;
+            (p, q)
+        })
+        .collect()
+}
+
+#[test]
+fn parse_errors_describe_the_problem() {
+    let error = "12.5(7".parse::<Rational>().unwrap_err();
+    assert_eq!(error.to_string(), "unclosed repeating part at byte 4");
+
+    let error: Box<dyn std::error::Error> = Box::new("1.x".parse::<Rational>().unwrap_err());
+    assert_eq!(
+        error.to_string(),
+        "invalid character in rational literal at byte 2"
+    );
+}
+
+#[test]
+fn parse_errors_point_at_byte_offsets() {
+    assert_eq!(
+        error_of("0.5€".parse::<Rational>()),
+        (RationalErrorKind::InvalidChar, 3)
+    );
+    assert_eq!(
+        error_of("€".parse::<Rational>()),
+        (RationalErrorKind::InvalidChar, 0)
+    );
+    assert_eq!(
+        error_of("-.".parse::<Rational>()),
+        (RationalErrorKind::Empty, 0)
+    );
+}
+
+#[test]
+fn it_parses_brackets() {
+    let a: Rational = "0.1[6]".parse().unwrap();
+    assert_eq!(a, Rational::new(1, 6));
+
+    let a: Rational = "-3.[571428]".parse().unwrap();
+    assert_eq!(a, Rational::new(-25, 7));
+
+    assert_eq!(
+        error_of("1.[]".parse::<Rational>()),
+        (RationalErrorKind::EmptyRepeating, 2)
+    );
+    assert_eq!(
+        error_of("1.[3".parse::<Rational>()),
+        (RationalErrorKind::UnclosedRepeating, 2)
+    );
+    assert_eq!(
+        error_of("1.[3)".parse::<Rational>()),
+        (RationalErrorKind::InvalidChar, 4)
+    );
+}
+
+#[test]
+fn it_parses_overlines() {
+    let a: Rational = "0.3\u{305}".parse().unwrap();
+    assert_eq!(a, Rational::new(1, 3));
+
+    let a: Rational = "0.16\u{305}".parse().unwrap();
+    assert_eq!(a, Rational::new(1, 6));
+
+    let a: Rational = "-3.5\u{305}7\u{305}1\u{305}4\u{305}2\u{305}8\u{305}"
+        .parse()
+        .unwrap();
+    assert_eq!(a, Rational::new(-25, 7));
+
+    let a: Rational = "0.3\u{305}e1".parse().unwrap();
+    assert_eq!(a, Rational::new(10, 3));
+
+    assert_eq!(
+        error_of("0.3\u{305}4".parse::<Rational>()),
+        (RationalErrorKind::InvalidChar, 5)
+    );
+    assert_eq!(
+        error_of("3\u{305}".parse::<Rational>()),
+        (RationalErrorKind::InvalidChar, 1)
+    );
+    assert_eq!(
+        error_of("0.\u{305}".parse::<Rational>()),
+        (RationalErrorKind::InvalidChar, 2)
+    );
+}
+
+#[test]
+fn it_infers_periods_before_ellipsis() {
+    let cases = [
+        ("0.333...", Rational::new(1, 3)),
+        ("0.33…", Rational::new(1, 3)),
+        ("0.1666...", Rational::new(1, 6)),
+        ("0.1212...", Rational::new(4, 33)),
+        ("0.142857142857...", Rational::new(1, 7)),
+        ("-3.571428571428...", Rational::new(-25, 7)),
+        ("0.12333...", Rational::new(37, 300)),
+        ("0.011011...", Rational::new(11, 999)),
+        ("0.99...", Rational::new(1, 1)),
+        ("0.11...e1", Rational::new(10, 9)),
+    ];
+    for (text, value) in cases {
+        assert_eq!(text.parse(), Ok(value), "{text}");
+    }
+}
+
+#[test]
+fn it_rejects_ellipsis_without_period() {
+    assert_eq!(
+        error_of("0.3...".parse::<Rational>()),
+        (RationalErrorKind::UnknownPeriod, 3)
+    );
+    assert_eq!(
+        error_of("0.12...".parse::<Rational>()),
+        (RationalErrorKind::UnknownPeriod, 4)
+    );
+    assert_eq!(
+        error_of("0....".parse::<Rational>()),
+        (RationalErrorKind::UnknownPeriod, 2)
+    );
+    assert_eq!(
+        error_of("0.33..".parse::<Rational>()),
+        (RationalErrorKind::InvalidChar, 4)
+    );
+    assert_eq!(
+        error_of("0.33....".parse::<Rational>()),
+        (RationalErrorKind::InvalidChar, 7)
+    );
+}
+
+#[test]
+fn it_formats_repeating_styles() {
+    let a = Rational::new(-25, 7);
+    assert_eq!(
+        a.to_repeating_decimal_with(RepeatingStyle::Parentheses),
+        "-3.(571428)"
+    );
+    assert_eq!(
+        a.to_repeating_decimal_with(RepeatingStyle::Brackets),
+        "-3.[571428]"
+    );
+    assert_eq!(
+        a.to_repeating_decimal_with(RepeatingStyle::Overline),
+        "-3.5\u{305}7\u{305}1\u{305}4\u{305}2\u{305}8\u{305}"
+    );
+    assert_eq!(
+        a.to_repeating_decimal_with(RepeatingStyle::Ellipsis),
+        "-3.571428571428..."
+    );
+
+    let a = Rational::new(3, 4);
+    for style in [
+        RepeatingStyle::Parentheses,
+        RepeatingStyle::Brackets,
+        RepeatingStyle::Overline,
+        RepeatingStyle::Ellipsis,
+    ] {
+        assert_eq!(a.to_repeating_decimal_with(style), "0.75");
+    }
+}
+
+#[test]
+fn repeating_styles_round_trip() {
+    let styles = [
+        RepeatingStyle::Parentheses,
+        RepeatingStyle::Brackets,
+        RepeatingStyle::Overline,
+        RepeatingStyle::Ellipsis,
+    ];
+    for q in 1..400 {
+        for p in [1, 2, 7, q - 1, 3 * q + 1, -11] {
+            let value = Rational::new(p, q);
+            for style in styles {
+                let text = value.to_repeating_decimal_with(style);
+                assert_eq!(text.parse(), Ok(value), "{text}");
+            }
+        }
+    }
 }
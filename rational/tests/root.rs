@@ -0,0 +1,199 @@
+use rational::*;
+
+#[test]
+fn it_finds_exact_roots() {
+    assert_eq!(Rational::new(0, 1).sqrt_exact(), Some(Rational::new(0, 1)));
+    assert_eq!(Rational::new(1, 1).sqrt_exact(), Some(Rational::new(1, 1)));
+    assert_eq!(
+        Rational::new(49, 64).sqrt_exact(),
+        Some(Rational::new(7, 8))
+    );
+    assert_eq!(Rational::new(48, 64).sqrt_exact(), None);
+    assert_eq!(Rational::new(1, 2).sqrt_exact(), None);
+    assert_eq!(Rational::new(-1, 1).sqrt_exact(), None);
+
+    assert_eq!(
+        Rational::new(-1, 1).nth_root_exact(3),
+        Some(Rational::new(-1, 1))
+    );
+    assert_eq!(
+        Rational::new(5, 7).nth_root_exact(1),
+        Some(Rational::new(5, 7))
+    );
+    assert_eq!(
+        Rational::new(1, 1024).nth_root_exact(10),
+        Some(Rational::new(1, 2))
+    );
+    assert_eq!(
+        Rational::new(1, 1024).nth_root_exact(5),
+        Some(Rational::new(1, 4))
+    );
+    assert_eq!(Rational::new(1, 1024).nth_root_exact(3), None);
+    assert_eq!(
+        Rational::new(-1, 1024).nth_root_exact(5),
+        Some(Rational::new(-1, 4))
+    );
+    assert_eq!(Rational::new(2, 1).nth_root_exact(1000), None);
+    assert_eq!(
+        Rational::new(1, 1).nth_root_exact(u32::MAX),
+        Some(Rational::new(1, 1))
+    );
+
+    assert_eq!(
+        Ratio::<i8>::new(-128, 1).nth_root_exact(1),
+        Some(Ratio::new(-128, 1))
+    );
+    assert_eq!(
+        Ratio::<i8>::new(-128, 1).nth_root_exact(7),
+        Some(Ratio::new(-2, 1))
+    );
+    assert_eq!(Ratio::new(i64::MAX, 1).sqrt_exact(), None);
+    assert_eq!(
+        Ratio::new(3037000499i64 * 3037000499, 1).sqrt_exact(),
+        Some(Ratio::new(3037000499, 1))
+    );
+
+    let big: BigRational = "1522756/1018081".parse().unwrap();
+    assert_eq!(
+        big.sqrt_exact(),
+        Some(Ratio::new(BigInt::from(1234), BigInt::from(1009)))
+    );
+    let big =
+        BigRational::from(BigInt::from(3)).pow(100) / BigRational::from(BigInt::from(2)).pow(60);
+    assert_eq!(
+        big.nth_root_exact(20),
+        Some(Ratio::new(BigInt::from(243), BigInt::from(8)))
+    );
+}
+
+#[test]
+#[should_panic(expected = "attempt to take a root of degree zero")]
+fn it_panics_on_roots_of_degree_zero() {
+    let _ = Rational::new(4, 1).nth_root_exact(0);
+}
+
+#[test]
+fn it_approximates_square_roots_with_bounded_denominators() {
+    let two = Ratio::<i64>::new(2, 1);
+    assert_eq!(two.sqrt_approx(1), Some(Ratio::new(1, 1)));
+    assert_eq!(two.sqrt_approx(2), Some(Ratio::new(3, 2)));
+    assert_eq!(two.sqrt_approx(12), Some(Ratio::new(17, 12)));
+    assert_eq!(two.sqrt_approx(100), Some(Ratio::new(140, 99)));
+
+    let three = Ratio::<i64>::new(3, 1);
+    assert_eq!(three.sqrt_approx(1000), Some(Ratio::new(1351, 780)));
+    assert_eq!(
+        Ratio::<i64>::new(1, 2).sqrt_approx(10),
+        Some(Ratio::new(7, 10))
+    );
+    assert_eq!(Ratio::<i64>::new(-2, 1).sqrt_approx(10), None);
+    assert_eq!(
+        Ratio::<i64>::new(0, 1).sqrt_approx(10),
+        Some(Ratio::new(0, 1))
+    );
+    assert_eq!(
+        Ratio::<i64>::new(4, 9).sqrt_approx(2),
+        Some(Ratio::new(1, 2))
+    );
+
+    // The closest fraction doesn't fit into i8, so the numerator is bounded instead
+    assert_eq!(
+        Ratio::<i8>::new(127, 1).sqrt_approx(127),
+        Some(Ratio::new(124, 11))
+    );
+}
+
+#[test]
+fn square_root_approximations_are_the_closest_fractions() {
+    for p in 0i64..=24 {
+        for q in 1..=4 {
+            let value = Ratio::new(p, q);
+            if value.sqrt_exact().is_some() {
+                continue;
+            }
+            for limit in 1..=20 {
+                let best = value.sqrt_approx(limit).unwrap();
+                assert!(best.denominator() <= limit);
+                for d in 1..=limit {
+                    let n = ((p as f64 / q as f64).sqrt() * d as f64) as i64;
+                    for n in [n - 1, n, n + 1, n + 2] {
+                        let candidate = Ratio::new(n, d);
+                        if candidate != best {
+                            assert!(
+                                closer(best, candidate, value),
+                                "{value} {limit} {candidate}"
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn it_approximates_square_roots_within_epsilon() {
+    let two = Ratio::<i64>::new(2, 1);
+    assert_eq!(two.sqrt_within(Ratio::new(1, 1)), Some(Ratio::new(1, 1)));
+    assert_eq!(two.sqrt_within(Ratio::new(1, 20)), Some(Ratio::new(7, 5)));
+    assert_eq!(two.sqrt_within(Ratio::new(9, 4)), Some(Ratio::new(1, 1)));
+    assert_eq!(
+        Ratio::<i64>::new(9, 16).sqrt_within(Ratio::new(1, 2)),
+        Some(Ratio::new(3, 4))
+    );
+    assert_eq!(
+        Ratio::<i64>::new(-9, 16).sqrt_within(Ratio::new(1, 2)),
+        None
+    );
+
+    let value = Ratio::<i8>::new(127, 1);
+    assert_eq!(
+        value.sqrt_within(Ratio::new(1, 10)),
+        Some(Ratio::new(34, 3))
+    );
+    assert_eq!(
+        value.sqrt_within(Ratio::new(1, 127)),
+        Some(Ratio::new(124, 11))
+    );
+    // The first convergent close enough is 181/32
+    assert_eq!(
+        Ratio::<i8>::new(32, 1).sqrt_within(Ratio::new(1, 127)),
+        None
+    );
+
+    for p in 1i64..=50 {
+        for epsilon in [
+            Ratio::new(1, 3),
+            Ratio::new(1, 1000),
+            Ratio::new(1, 1 << 40),
+        ] {
+            let value = Ratio::new(p, 7);
+            let root = value.sqrt_within(epsilon).unwrap();
+            let float = (p as f64 / 7.0).sqrt();
+            assert!(
+                (root.to_f64() - float).abs() <= epsilon.to_f64() * 1.000001,
+                "{value}"
+            );
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "epsilon must be positive")]
+fn it_panics_on_non_positive_epsilon() {
+    let _ = Rational::new(2, 1).sqrt_within(Rational::new(0, 1));
+}
+
+/// Whether `a` is strictly closer to the square root of `value` than `b`.
+fn closer(a: Ratio<i64>, b: Ratio<i64>, value: Ratio<i64>) -> bool {
+    let (a, b, value) = (
+        BigRational::from(a),
+        BigRational::from(b),
+        BigRational::from(value),
+    );
+    let midpoint = (a.clone() + b.clone()) / BigRational::from(BigInt::from(2));
+    if midpoint < BigRational::from(BigInt::from(0)) {
+        return a > b;
+    }
+    (a < b) == (value < midpoint.clone() * midpoint)
+}
@@ -0,0 +1,132 @@
+use rational::*;
+
+#[test]
+fn it_rounds_towards_infinities_and_zero() {
+    let cases = [
+        ((7, 2), (3, 4, 3)),
+        ((-7, 2), (-4, -3, -3)),
+        ((1, 3), (0, 1, 0)),
+        ((-1, 3), (-1, 0, 0)),
+        ((6, 1), (6, 6, 6)),
+        ((-6, 1), (-6, -6, -6)),
+        ((0, 1), (0, 0, 0)),
+    ];
+    for ((p, q), (floor, ceil, trunc)) in cases {
+        let value = Ratio::<i64>::new(p, q);
+        assert_eq!(
+            (value.floor(), value.ceil(), value.trunc()),
+            (floor, ceil, trunc),
+            "{value}"
+        );
+    }
+}
+
+#[test]
+fn it_rounds_halves_according_to_the_mode() {
+    use RoundingMode::*;
+
+    // Expected results for HalfUp, HalfDown, HalfEven and HalfAwayFromZero
+    let cases = [
+        ((5, 2), [3, 2, 2, 3]),
+        ((-5, 2), [-2, -3, -2, -3]),
+        ((7, 2), [4, 3, 4, 4]),
+        ((-7, 2), [-3, -4, -4, -4]),
+        ((1, 2), [1, 0, 0, 1]),
+        ((-1, 2), [0, -1, 0, -1]),
+        ((8, 3), [3, 3, 3, 3]),
+        ((-8, 3), [-3, -3, -3, -3]),
+        ((7, 3), [2, 2, 2, 2]),
+        ((-7, 3), [-2, -2, -2, -2]),
+    ];
+    for ((p, q), expected) in cases {
+        let value = Ratio::<i64>::new(p, q);
+        let rounded =
+            [HalfUp, HalfDown, HalfEven, HalfAwayFromZero].map(|mode| value.round_with(mode));
+        assert_eq!(rounded, expected, "{value}");
+        assert_eq!(value.round(), expected[3], "{value}");
+    }
+    assert_eq!(RoundingMode::default(), HalfAwayFromZero);
+}
+
+#[test]
+fn it_agrees_with_float_rounding() {
+    for p in i8::MIN..=i8::MAX {
+        for q in 1..=i8::MAX {
+            let value = Ratio::<i8>::new(p, q);
+            let float = p as f64 / q as f64;
+            assert_eq!(value.floor() as f64, float.floor(), "{value}");
+            assert_eq!(value.ceil() as f64, float.ceil(), "{value}");
+            assert_eq!(value.trunc() as f64, float.trunc(), "{value}");
+            assert_eq!(value.round() as f64, float.round(), "{value}");
+            assert_eq!(
+                value.round_with(RoundingMode::HalfEven) as f64,
+                float.round_ties_even(),
+                "{value}"
+            );
+            assert_eq!(
+                value.round_with(RoundingMode::HalfUp) as f64,
+                (float + 0.5).floor(),
+                "{value}"
+            );
+            assert_eq!(
+                value.round_with(RoundingMode::HalfDown) as f64,
+                (float - 0.5).ceil(),
+                "{value}"
+            );
+            let fract = value.fract();
+            assert_eq!(Ratio::from(value.trunc()) + fract, value);
+            assert!(fract.numerator() == 0 || (fract.numerator() < 0) == (p < 0));
+        }
+    }
+}
+
+#[test]
+fn it_rounds_extreme_values_without_overflowing() {
+    let min = Ratio::new(i64::MIN, 1);
+    assert_eq!(
+        (min.floor(), min.ceil(), min.round()),
+        (i64::MIN, i64::MIN, i64::MIN)
+    );
+    assert_eq!(min.fract(), Ratio::new(0, 1));
+
+    let value = Ratio::new(i64::MIN, i64::MAX);
+    assert_eq!((value.floor(), value.ceil(), value.round()), (-2, -1, -1));
+    assert_eq!(value.fract(), Ratio::new(-1, i64::MAX));
+
+    let value = Ratio::new(i64::MAX, 2);
+    assert_eq!(value.round_with(RoundingMode::HalfUp), i64::MAX / 2 + 1);
+    assert_eq!(value.round_with(RoundingMode::HalfEven), i64::MAX / 2 + 1);
+    assert_eq!(value.round_with(RoundingMode::HalfDown), i64::MAX / 2);
+
+    let big: BigRational = "-123456789012345678901234567890.5".parse().unwrap();
+    let expected: BigInt = "-123456789012345678901234567890".parse().unwrap();
+    assert_eq!(big.round_with(RoundingMode::HalfEven), expected);
+    assert_eq!(big.ceil(), expected);
+    assert_eq!(big.floor(), expected - BigInt::from(1));
+    assert_eq!(big.fract(), Ratio::new(BigInt::from(-1), BigInt::from(2)));
+}
+
+#[test]
+fn it_converts_integral_values_into_integers() {
+    assert_eq!(i32::try_from(Ratio::<i64>::new(42, 1)), Ok(42));
+    assert_eq!(u8::try_from(Ratio::<i64>::new(255, 1)), Ok(255));
+    assert_eq!(i8::try_from(Ratio::<i64>::new(-128, 1)), Ok(-128));
+    assert_eq!(u128::try_from(Ratio::<i8>::new(0, 1)), Ok(0));
+    assert_eq!(i128::try_from(Ratio::new(i128::MIN, 1)), Ok(i128::MIN));
+    assert_eq!(usize::try_from(Ratio::<isize>::new(7, 1)), Ok(7));
+    assert_eq!(
+        BigInt::try_from(Ratio::<i64>::new(-5, 1)),
+        Ok(BigInt::from(-5))
+    );
+
+    let error = i32::try_from(Ratio::<i64>::new(1, 2)).unwrap_err();
+    assert_eq!(error.to_string(), "rational is not an integer");
+    let error = u8::try_from(Ratio::<i64>::new(256, 1)).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "rational out of range of the target type"
+    );
+    assert!(u32::try_from(Ratio::<i64>::new(-1, 1)).is_err());
+    assert!(i64::try_from(Ratio::<i64>::new(-1, 3)).is_err());
+    assert!(BigInt::try_from(BigRational::new(BigInt::from(3), BigInt::from(6))).is_err());
+}
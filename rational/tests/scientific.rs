@@ -0,0 +1,81 @@
+mod common;
+
+use common::error_of;
+use rational::*;
+
+#[test]
+fn it_parses_exponents() {
+    assert_eq!("1.25e-7".parse(), Ok(Rational::new(1, 8_000_000)));
+    assert_eq!("3.(3)E2".parse(), Ok(Rational::new(1000, 3)));
+    assert_eq!("-1.5e3".parse(), Ok(Rational::new(-1500, 1)));
+    assert_eq!("2e+2".parse(), Ok(Rational::new(200, 1)));
+    assert_eq!("5E0".parse(), Ok(Rational::new(5, 1)));
+    assert_eq!(".5e1".parse(), Ok(Rational::new(5, 1)));
+    assert_eq!("7.e-1".parse(), Ok(Rational::new(7, 10)));
+    assert_eq!("0.(142857)e6".parse(), Ok(Rational::new(1_000_000, 7)));
+    assert_eq!("-0.1(6)e-02".parse(), Ok(Rational::new(-1, 600)));
+}
+
+#[test]
+fn it_ignores_huge_exponents_of_zero() {
+    assert_eq!("0e99999999999999999999".parse(), Ok(Rational::new(0, 1)));
+    assert_eq!("-0.(0)e-4000000000".parse(), Ok(Rational::new(0, 1)));
+    assert_eq!(
+        "0.000e999999999".parse::<BigRational>(),
+        Ok(Ratio::from(BigInt::from(0)))
+    );
+}
+
+#[test]
+fn it_reports_exponent_overflow() {
+    for text in [
+        "1e19",
+        "1e-19",
+        "1e4000000000",
+        "1e99999999999999999999",
+        "9.3e18",
+    ] {
+        let error = text.parse::<Ratio<i64>>().unwrap_err();
+        assert_eq!(
+            (error.kind(), error.position()),
+            (RationalErrorKind::Overflow, 0),
+            "{text}"
+        );
+    }
+
+    assert_eq!("1e18".parse(), Ok(Ratio::<i64>::new(10i64.pow(18), 1)));
+    assert_eq!("1e-18".parse(), Ok(Ratio::<i64>::new(1, 10i64.pow(18))));
+    assert_eq!(
+        "0.0000000000000000000000000000000000009e36".parse(),
+        Ok(Ratio::<i8>::new(9, 10))
+    );
+    assert_eq!(
+        "12000000000000000000000000000000000000000e-39".parse(),
+        Ok(Ratio::<i8>::new(12, 1))
+    );
+    assert_eq!("1.(27)e-1".parse(), Ok(Ratio::<i8>::new(7, 55)));
+}
+
+#[test]
+fn it_limits_exponents_of_big_rationals() {
+    let a: BigRational = "1e300".parse().unwrap();
+    assert_eq!(a.to_string(), format!("1{}", "0".repeat(300)));
+
+    let error = "1e100001".parse::<BigRational>().unwrap_err();
+    assert_eq!(error.kind(), RationalErrorKind::Overflow);
+}
+
+#[test]
+fn it_rejects_malformed_exponents() {
+    let error_of = |text: &str| error_of(text.parse::<Rational>());
+
+    assert_eq!(error_of("1e"), (RationalErrorKind::Empty, 2));
+    assert_eq!(error_of("1e-"), (RationalErrorKind::Empty, 3));
+    assert_eq!(error_of("1e+-2"), (RationalErrorKind::InvalidChar, 3));
+    assert_eq!(error_of("1e2.5"), (RationalErrorKind::InvalidChar, 3));
+    assert_eq!(error_of("1e2e3"), (RationalErrorKind::InvalidChar, 3));
+    assert_eq!(error_of("e5"), (RationalErrorKind::InvalidChar, 0));
+    assert_eq!(error_of(".e5"), (RationalErrorKind::InvalidChar, 1));
+    assert_eq!(error_of("1/2e3"), (RationalErrorKind::InvalidChar, 3));
+    assert_eq!(error_of("0.(3e2)"), (RationalErrorKind::InvalidChar, 4));
+}
@@ -0,0 +1,51 @@
+use rational::*;
+
+macro_rules! check_width {
+    ($($name:ident: $int:ty),* $(,)?) => {$(
+        #[test]
+        fn $name() {
+            let a: Ratio<$int> = Ratio::new(15, -6);
+            let b: Ratio<$int> = "-2.5".parse().unwrap();
+            assert_eq!(a, b);
+
+            let c: Ratio<$int> = "0.(3)".parse().unwrap();
+            assert_eq!(c, Ratio::new(1, 3));
+
+            assert_eq!(c + c + c, Ratio::from(1));
+            assert_eq!(a - c, Ratio::new(-17, 6));
+            assert_eq!(a * c, Ratio::new(-5, 6));
+            assert_eq!(a / c, Ratio::new(-15, 2));
+            assert_eq!(-a, Ratio::new(5, 2));
+
+            assert!(a < c);
+            assert!(c > Ratio::from(0));
+        }
+    )*};
+}
+
+check_width! {
+    works_with_i8: i8,
+    works_with_i16: i16,
+    works_with_i32: i32,
+    works_with_i64: i64,
+    works_with_i128: i128,
+    works_with_isize: isize,
+}
+
+#[test]
+fn reduces_minimal_values() {
+    let a: Ratio<i8> = Ratio::new(i8::MIN, 2);
+    assert_eq!(a, Ratio::from(-64));
+
+    let a: Ratio<i128> = Ratio::new(i128::MAX, i128::MAX);
+    assert_eq!(a, Ratio::from(1));
+}
+
+#[test]
+#[cfg(target_pointer_width = "64")]
+fn default_type_is_isize() {
+    let a = Rational::new(100_000, 1);
+    assert_eq!(a * a, Rational::new(10_000_000_000, 1));
+    assert_eq!(Rational::new(5_000_000_000, 3).numerator(), 5_000_000_000);
+    assert_eq!(Rational::from(isize::MAX).numerator(), isize::MAX);
+}